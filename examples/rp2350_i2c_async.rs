@@ -1,7 +1,7 @@
 #![no_std]
 #![no_main]
 
-use acs37800::prelude::*;
+use acs37800::prelude_async::*;
 use defmt::{Debug2Format, info, warn};
 use defmt_rtt as _;
 use embassy_executor::Spawner;
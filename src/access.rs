@@ -0,0 +1,313 @@
+use core::ops::{Deref, DerefMut};
+
+use thiserror::Error;
+
+use crate::{Acs37800, Acs37800ReadError, Acs37800Register, Acs37800WriteError};
+
+#[cfg(feature = "async")]
+use crate::Acs37800Async;
+
+/// ## Customer access code.
+///
+/// Writing this value to register 0x2F grants write access to the EEPROM (0x0B–0x0F) and shadow
+/// (0x1B–0x1F) registers until the code is cleared or the device is power-cycled.
+pub const CUSTOMER_ACCESS_CODE: u32 = 0x4F70_656E;
+
+/// Value written to register 0x2F to revoke customer write access.
+const CUSTOMER_ACCESS_LOCK: u32 = 0;
+
+#[derive(Debug, Error)]
+pub enum Acs37800AccessError {
+    #[error(transparent)]
+    Read(#[from] Acs37800ReadError),
+    #[error(transparent)]
+    Write(#[from] Acs37800WriteError),
+    #[error("Device did not grant customer write access")]
+    Denied,
+}
+
+pub trait Acs37800AccessExt: Acs37800 {
+    /// Reads register 0x30 to determine whether customer write access is currently granted.
+    fn is_unlocked(&mut self) -> Result<bool, Acs37800ReadError> {
+        Ok(self.read_reg32(Acs37800Register::R30)? & 1 != 0)
+    }
+
+    /// Writes the customer access code and confirms the device granted access.
+    ///
+    /// The device is locked again when the returned guard is released with
+    /// [`Acs37800Unlocked::lock`] or dropped.
+    fn unlock(&mut self) -> Result<Acs37800Unlocked<'_, Self>, Acs37800AccessError> {
+        self.write_reg32(Acs37800Register::R2F, CUSTOMER_ACCESS_CODE)?;
+        let granted = match self.is_unlocked() {
+            Ok(granted) => granted,
+            Err(err) => {
+                // Access may have been granted, no guard exists yet to revoke it
+                let _ = self.write_reg32(Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK);
+                return Err(err.into());
+            }
+        };
+        if !granted {
+            return Err(Acs37800AccessError::Denied);
+        }
+        Ok(Acs37800Unlocked {
+            device: self,
+            relock: true,
+        })
+    }
+}
+
+impl<T: Acs37800 + ?Sized> Acs37800AccessExt for T {}
+
+#[cfg(feature = "async")]
+pub trait Acs37800AccessAsyncExt: Acs37800Async {
+    /// Reads register 0x30 to determine whether customer write access is currently granted.
+    fn is_unlocked(&mut self) -> impl Future<Output = Result<bool, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_reg32(Acs37800Register::R30).await? & 1 != 0) }
+    }
+
+    /// Writes the customer access code and confirms the device granted access.
+    ///
+    /// The returned guard must be released with [`Acs37800UnlockedAsync::lock`], the device
+    /// otherwise remains unlocked until it is power-cycled.
+    fn unlock(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800UnlockedAsync<'_, Self>, Acs37800AccessError>> + '_
+    {
+        async {
+            self.write_reg32(Acs37800Register::R2F, CUSTOMER_ACCESS_CODE)
+                .await?;
+            let granted = match self.is_unlocked().await {
+                Ok(granted) => granted,
+                Err(err) => {
+                    // Access may have been granted, no guard exists yet to revoke it
+                    let _ = self
+                        .write_reg32(Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK)
+                        .await;
+                    return Err(err.into());
+                }
+            };
+            if !granted {
+                return Err(Acs37800AccessError::Denied);
+            }
+            Ok(Acs37800UnlockedAsync { device: self })
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: Acs37800Async + ?Sized> Acs37800AccessAsyncExt for T {}
+
+/// Device with customer write access granted.
+///
+/// Dereferences to the underlying device so every read and write helper stays available while
+/// unlocked.
+pub struct Acs37800Unlocked<'a, D: Acs37800 + ?Sized> {
+    device: &'a mut D,
+    relock: bool,
+}
+
+impl<D: Acs37800 + ?Sized> Acs37800Unlocked<'_, D> {
+    /// Clears the customer access code, revoking write access.
+    pub fn lock(mut self) -> Result<(), Acs37800WriteError> {
+        self.relock = false;
+        self.device
+            .write_reg32(Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK)
+    }
+}
+
+impl<D: Acs37800 + ?Sized> Deref for Acs37800Unlocked<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        self.device
+    }
+}
+
+impl<D: Acs37800 + ?Sized> DerefMut for Acs37800Unlocked<'_, D> {
+    fn deref_mut(&mut self) -> &mut D {
+        self.device
+    }
+}
+
+/// Best-effort relock, use [`Acs37800Unlocked::lock`] to observe failures.
+impl<D: Acs37800 + ?Sized> Drop for Acs37800Unlocked<'_, D> {
+    fn drop(&mut self) {
+        if self.relock {
+            let _ = self
+                .device
+                .write_reg32(Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK);
+        }
+    }
+}
+
+/// Async device with customer write access granted.
+///
+/// Unlike [`Acs37800Unlocked`] it cannot relock on drop, release it with
+/// [`lock`](Self::lock).
+#[cfg(feature = "async")]
+pub struct Acs37800UnlockedAsync<'a, D: Acs37800Async + ?Sized> {
+    device: &'a mut D,
+}
+
+#[cfg(feature = "async")]
+impl<D: Acs37800Async + ?Sized> Acs37800UnlockedAsync<'_, D> {
+    /// Clears the customer access code, revoking write access.
+    pub async fn lock(self) -> Result<(), Acs37800WriteError> {
+        self.device
+            .write_reg32(Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK)
+            .await
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: Acs37800Async + ?Sized> Deref for Acs37800UnlockedAsync<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        self.device
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: Acs37800Async + ?Sized> DerefMut for Acs37800UnlockedAsync<'_, D> {
+    fn deref_mut(&mut self) -> &mut D {
+        self.device
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{MockDevice, assert_is_bus_error};
+
+    use super::*;
+
+    #[test]
+    fn unlock_writes_access_code_and_confirms_status() {
+        let mut mock = MockDevice::default();
+
+        let unlocked = mock.unlock().expect("unlocked");
+        unlocked.lock().expect("locked");
+
+        assert_eq!(
+            mock.writes,
+            [
+                (Acs37800Register::R2F, CUSTOMER_ACCESS_CODE),
+                (Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK),
+            ]
+        );
+        assert!(!mock.is_unlocked().expect("status"));
+    }
+
+    #[test]
+    fn dropping_guard_relocks() {
+        let mut mock = MockDevice::default();
+
+        {
+            let mut unlocked = mock.unlock().expect("unlocked");
+            assert!(unlocked.is_unlocked().expect("status"));
+        }
+
+        assert!(!mock.is_unlocked().expect("status"));
+        assert_eq!(mock.writes.len(), 2);
+    }
+
+    #[test]
+    fn unlock_reports_denied_access() {
+        let mut mock = DenyingDevice(MockDevice::default());
+        let err = mock.unlock().err().expect("should be denied");
+        assert!(matches!(err, Acs37800AccessError::Denied));
+    }
+
+    #[test]
+    fn unlock_propagates_status_read_errors() {
+        let mut mock = MockDevice::with_failure(Acs37800Register::R30);
+
+        let err = mock.unlock().err().expect("should fail");
+        match err {
+            Acs37800AccessError::Read(err) => assert_is_bus_error(&err),
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unlock_relocks_when_status_read_fails() {
+        let mut mock = MockDevice::with_failure(Acs37800Register::R30);
+
+        mock.unlock().err().expect("should fail");
+
+        assert_eq!(
+            mock.writes,
+            [
+                (Acs37800Register::R2F, CUSTOMER_ACCESS_CODE),
+                (Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK),
+            ]
+        );
+    }
+
+    /// Device that ignores the access code, as if it were wrong or write-protected.
+    struct DenyingDevice(MockDevice);
+
+    impl Acs37800 for DenyingDevice {
+        fn read_reg32(&mut self, reg: Acs37800Register) -> Result<u32, Acs37800ReadError> {
+            self.0.read_reg32(reg)
+        }
+
+        fn write_reg32(
+            &mut self,
+            reg: Acs37800Register,
+            _value: u32,
+        ) -> Result<(), Acs37800WriteError> {
+            self.0.write_reg32(reg, CUSTOMER_ACCESS_LOCK)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use crate::test::{AsyncMockDevice, MockDevice, assert_is_bus_error};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn unlock_writes_access_code_and_confirms_status_async() {
+        let mut mock = AsyncMockDevice::default();
+
+        let unlocked = mock.unlock().await.expect("unlocked");
+        unlocked.lock().await.expect("locked");
+
+        assert_eq!(
+            mock.writes,
+            [
+                (Acs37800Register::R2F, CUSTOMER_ACCESS_CODE),
+                (Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK),
+            ]
+        );
+        assert!(!mock.is_unlocked().await.expect("status"));
+    }
+
+    #[tokio::test]
+    async fn unlock_propagates_status_read_errors_async() {
+        let mut mock = AsyncMockDevice(MockDevice::with_failure(Acs37800Register::R30));
+
+        let err = mock.unlock().await.err().expect("should fail");
+        match err {
+            Acs37800AccessError::Read(err) => assert_is_bus_error(&err),
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn unlock_relocks_when_status_read_fails_async() {
+        let mut mock = AsyncMockDevice(MockDevice::with_failure(Acs37800Register::R30));
+
+        mock.unlock().await.err().expect("should fail");
+
+        assert_eq!(
+            mock.writes,
+            [
+                (Acs37800Register::R2F, CUSTOMER_ACCESS_CODE),
+                (Acs37800Register::R2F, CUSTOMER_ACCESS_LOCK),
+            ]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use bon::Builder;
+use thiserror::Error;
+
+use crate::{
+    Acs37800AveragedQuantity, Acs37800Dio0Function, Acs37800Dio1Function, Acs37800Eeprom,
+    Acs37800EepromRaw, Acs37800PhaseCompensation, Acs37800RangeError, Acs37800Scaling,
+};
+
+const I2C_ADDRESS_MAX: u8 = 0x7f;
+
+/// Configuration that cannot be encoded into the EEPROM/shadow registers.
+#[derive(Debug, Error, Clone, Copy, PartialEq)]
+pub enum Acs37800ConfigError {
+    #[error(transparent)]
+    Range(#[from] Acs37800RangeError),
+    #[error("Board scaling is required to encode fault and voltage thresholds")]
+    NoScaling,
+}
+
+/// ## Device configuration in engineering units.
+///
+/// Every setting is optional, [`Acs37800Config::apply`] only touches the bitfields of the
+/// settings that are present and leaves everything else, reserved bits included, as read from the
+/// device. A typical update reads the current content with
+/// [`read_shadow_raw`](crate::Acs37800EepromExt::read_shadow_raw), applies the configuration and
+/// writes the result back with `write_shadow_raw` or `program_eeprom`.
+#[derive(Builder, Clone, Copy, Debug, PartialEq)]
+pub struct Acs37800Config {
+    /// Board scaling, required to encode the fault and overvoltage/undervoltage thresholds. Its
+    /// variant sets the scale of the fault threshold.
+    pub scaling: Option<Acs37800Scaling>,
+    /// Line frequency the averaging windows and voltage event duration are based on, 50 Hz or
+    /// 60 Hz mains.
+    pub line_frequency_hz: f32,
+
+    // Register 0x0B
+    pub averaged_quantity: Option<Acs37800AveragedQuantity>,
+
+    // Register 0x0C
+    /// Window of the first averager (one-second registers) in seconds.
+    pub one_sec_average_s: Option<f32>,
+    /// Window of the second averager (one-minute registers) in seconds.
+    pub one_min_average_s: Option<f32>,
+
+    // Register 0x0D
+    pub fault_threshold_amps: Option<f32>,
+    pub fault_delay_us: Option<f32>,
+    pub phase_compensation: Option<Acs37800PhaseCompensation>,
+
+    // Register 0x0E
+    /// Overvoltage threshold in RMS volts.
+    pub overvoltage_threshold_volts: Option<f32>,
+    /// Undervoltage threshold in RMS volts.
+    pub undervoltage_threshold_volts: Option<f32>,
+    /// Time the voltage must stay beyond the OV/UV thresholds before the event is flagged.
+    pub vevent_duration_ms: Option<f32>,
+
+    // Register 0x0F
+    /// 7-bit I2C address, setting it also sets `i2c_dis_slv_addr` so the device uses this address
+    /// instead of the one selected by the DIO pins.
+    pub i2c_address: Option<u8>,
+    pub dio0_function: Option<Acs37800Dio0Function>,
+    pub dio1_function: Option<Acs37800Dio1Function>,
+}
+
+impl Acs37800Config {
+    /// Encodes the configuration on top of `raw`, the content of the EEPROM or shadow registers.
+    ///
+    /// Values are rounded to the nearest code, values outside the range of their field are
+    /// rejected, as is a line frequency that is not positive and finite.
+    pub fn apply(&self, raw: Acs37800EepromRaw) -> Result<Acs37800EepromRaw, Acs37800ConfigError> {
+        Acs37800RangeError::check(
+            "line frequency (Hz)",
+            self.line_frequency_hz,
+            f32::MIN_POSITIVE,
+            f32::MAX,
+        )?;
+
+        let Acs37800EepromRaw {
+            mut r0b,
+            r0c,
+            mut r0d,
+            mut r0e,
+            mut r0f,
+        } = raw;
+
+        // 0x0B
+        if let Some(quantity) = self.averaged_quantity {
+            r0b = r0b
+                .with_iavgselen(quantity == Acs37800AveragedQuantity::Current)
+                .with_pavgselen(quantity == Acs37800AveragedQuantity::ActivePower);
+        }
+
+        // 0x0D
+        if let Some(amps) = self.fault_threshold_amps {
+            r0d = r0d.with_fault(self.scaling()?.variant.fault_threshold_codes(amps)?);
+        }
+        if let Some(delay_us) = self.fault_delay_us {
+            r0d = r0d.with_fault_delay_us(delay_us)?;
+        }
+        if let Some(compensation) = self.phase_compensation {
+            r0d = r0d.with_phase_compensation(compensation)?;
+        }
+
+        // 0x0E
+        if let Some(volts) = self.overvoltage_threshold_volts {
+            r0e = r0e.with_overvreg(self.scaling()?.voltage_threshold_codes(volts)?);
+        }
+        if let Some(volts) = self.undervoltage_threshold_volts {
+            r0e = r0e.with_undervreg(self.scaling()?.voltage_threshold_codes(volts)?);
+        }
+        if let Some(duration_ms) = self.vevent_duration_ms {
+            let cycles =
+                Acs37800Eeprom::vevent_cycles_from_ms(duration_ms, self.line_frequency_hz)?;
+            r0e = r0e.with_vevent_cycs(cycles);
+        }
+
+        // 0x0F
+        if let Some(address) = self.i2c_address {
+            Acs37800RangeError::check(
+                "i2c address",
+                f32::from(address),
+                0.0,
+                f32::from(I2C_ADDRESS_MAX),
+            )?;
+            r0f = r0f.with_i2c_slv_addr(address).with_i2c_dis_slv_addr(true);
+        }
+        if let Some(function) = self.dio0_function {
+            r0f = r0f.with_dio0_function(function);
+        }
+        if let Some(function) = self.dio1_function {
+            r0f = r0f.with_dio1_function(function);
+        }
+
+        // 0x0C, the averager counts depend on the RMS window (0x0F) and on each other
+        let mut raw = Acs37800EepromRaw {
+            r0b,
+            r0c,
+            r0d,
+            r0e,
+            r0f,
+        };
+        if let Some(seconds) = self.one_sec_average_s {
+            let rms_avg_1 =
+                Acs37800Eeprom::from(raw).rms_avg_1_for_seconds(seconds, self.line_frequency_hz)?;
+            raw.r0c = raw.r0c.with_rms_avg_1(rms_avg_1);
+        }
+        if let Some(seconds) = self.one_min_average_s {
+            let rms_avg_2 =
+                Acs37800Eeprom::from(raw).rms_avg_2_for_seconds(seconds, self.line_frequency_hz)?;
+            raw.r0c = raw.r0c.with_rms_avg_2(rms_avg_2);
+        }
+
+        Ok(raw)
+    }
+
+    fn scaling(&self) -> Result<Acs37800Scaling, Acs37800ConfigError> {
+        self.scaling.ok_or(Acs37800ConfigError::NoScaling)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Acs37800DelayedChannel, Acs37800Variant, Eeprom0cRaw, Eeprom0dRaw, Eeprom0eRaw, Eeprom0fRaw,
+    };
+
+    use super::*;
+
+    /// Register content with every reserved bit set.
+    fn reserved_raw() -> Acs37800EepromRaw {
+        Acs37800EepromRaw::builder()
+            .r0b(0x0300_0000)
+            .r0c(0x0200_0000)
+            .r0d(0x0300_117f)
+            .r0e(0x0200_00c0)
+            .r0f(0x0200_0003)
+            .build()
+    }
+
+    fn scaling() -> Acs37800Scaling {
+        Acs37800Scaling::builder()
+            .divider_resistance_ohms(999_000.0)
+            .sense_resistance_ohms(1_000.0)
+            .variant(Acs37800Variant::Range30A)
+            .build()
+            .expect("valid scaling")
+    }
+
+    #[test]
+    fn empty_config_leaves_registers_untouched() {
+        let raw = reserved_raw();
+        let config = Acs37800Config::builder().line_frequency_hz(60.0).build();
+        let applied = config.apply(raw).expect("nothing to encode");
+        assert_eq!(applied.eeprom_words(), raw.eeprom_words());
+    }
+
+    #[test]
+    fn settings_encode_into_their_fields() {
+        let config = Acs37800Config::builder()
+            .scaling(scaling())
+            .line_frequency_hz(50.0)
+            .averaged_quantity(Acs37800AveragedQuantity::ActivePower)
+            .one_sec_average_s(1.0)
+            .one_min_average_s(60.0)
+            .fault_threshold_amps(15.0)
+            .fault_delay_us(20.0)
+            .phase_compensation(Acs37800PhaseCompensation {
+                channel: Acs37800DelayedChannel::Current,
+                delay_us: 13.25,
+            })
+            .overvoltage_threshold_volts(scaling().voltage_threshold_volts(40))
+            .undervoltage_threshold_volts(scaling().voltage_threshold_volts(20))
+            .vevent_duration_ms(100.0)
+            .i2c_address(0x61)
+            .dio0_function(Acs37800Dio0Function::Undervoltage)
+            .dio1_function(Acs37800Dio1Function::Overvoltage)
+            .build();
+
+        let raw = config.apply(reserved_raw()).expect("in range");
+        let eeprom = Acs37800Eeprom::from(raw);
+        assert!(eeprom.pavgsel_enabled);
+        assert!(!eeprom.iavgsel_enabled);
+        assert_eq!(eeprom.rms_avg_1, 50);
+        assert_eq!(eeprom.rms_avg_2, 60);
+        assert_eq!(eeprom.fault_threshold_codes, 64);
+        assert_eq!(eeprom.fault_delay_setting, 5);
+        assert!(eeprom.ichan_delay_enabled);
+        assert_eq!(eeprom.chan_delay_sel, 3);
+        assert_eq!(eeprom.overvoltage_threshold_codes, 40);
+        assert_eq!(eeprom.undervoltage_threshold_codes, 20);
+        assert_eq!(eeprom.vevent_cycles, 5);
+        assert_eq!(eeprom.i2c_address_7bit, 0x61);
+        assert!(eeprom.i2c_address_disabled);
+        assert_eq!(eeprom.dio0_function, Acs37800Dio0Function::Undervoltage);
+        assert_eq!(eeprom.dio1_function, Acs37800Dio1Function::Overvoltage);
+
+        // Reserved bits survive
+        assert_eq!(raw.r0b.into_bits() & 0x0300_0000, 0x0300_0000);
+        assert_eq!(raw.r0c.into_bits() & 0x0200_0000, 0x0200_0000);
+        assert_eq!(raw.r0d.into_bits() & 0x0300_117f, 0x0300_117f);
+        assert_eq!(raw.r0e.into_bits() & 0x0200_00c0, 0x0200_00c0);
+        assert_eq!(raw.r0f.into_bits() & 0x0200_0003, 0x0200_0003);
+    }
+
+    #[test]
+    fn averaging_follows_configured_rms_window() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(0)
+            .r0c(Eeprom0cRaw::new())
+            .r0d(Eeprom0dRaw::new())
+            .r0e(Eeprom0eRaw::new())
+            .r0f(Eeprom0fRaw::new().with_n(320).with_bypass_n_en(true))
+            .build();
+        let config = Acs37800Config::builder()
+            .line_frequency_hz(60.0)
+            .one_sec_average_s(0.1)
+            .build();
+
+        let raw = config.apply(raw).expect("in range");
+        assert_eq!(raw.r0c.rms_avg_1(), 10);
+    }
+
+    #[test]
+    fn i2c_address_overrides_dio_addressing() {
+        let config = Acs37800Config::builder()
+            .line_frequency_hz(60.0)
+            .i2c_address(0x61)
+            .build();
+
+        let raw = config.apply(reserved_raw()).expect("in range");
+        // I2C_DIS_SLV_ADDR, bit 9 of 0x0F
+        assert_eq!(raw.r0f.into_bits() >> 9 & 1, 1);
+        assert_eq!(raw.r0f.i2c_slv_addr(), 0x61);
+    }
+
+    #[test]
+    fn out_of_range_settings_are_rejected() {
+        let config = Acs37800Config::builder()
+            .scaling(Acs37800Scaling {
+                variant: Acs37800Variant::Range15A,
+                ..scaling()
+            })
+            .line_frequency_hz(60.0)
+            .fault_threshold_amps(40.0)
+            .build();
+        let err = config.apply(reserved_raw()).expect_err("above range");
+        assert!(matches!(err, Acs37800ConfigError::Range(range) if range.value == 40.0));
+
+        let config = Acs37800Config::builder()
+            .line_frequency_hz(60.0)
+            .i2c_address(0x80)
+            .build();
+        assert!(config.apply(reserved_raw()).is_err());
+    }
+
+    #[test]
+    fn line_frequency_must_be_positive_and_finite() {
+        for line_frequency_hz in [0.0, -50.0, f32::NAN, f32::INFINITY] {
+            let config = Acs37800Config::builder()
+                .line_frequency_hz(line_frequency_hz)
+                .build();
+            let err = config
+                .apply(reserved_raw())
+                .expect_err("invalid line frequency");
+            assert!(
+                matches!(err, Acs37800ConfigError::Range(range) if range.field == "line frequency (Hz)")
+            );
+        }
+    }
+
+    #[test]
+    fn thresholds_need_scaling() {
+        let config = Acs37800Config::builder()
+            .line_frequency_hz(60.0)
+            .overvoltage_threshold_volts(120.0)
+            .build();
+        assert_eq!(
+            config.apply(reserved_raw()).expect_err("no scaling"),
+            Acs37800ConfigError::NoScaling
+        );
+
+        let config = Acs37800Config::builder()
+            .line_frequency_hz(60.0)
+            .fault_threshold_amps(10.0)
+            .build();
+        assert_eq!(
+            config.apply(reserved_raw()).expect_err("no scaling"),
+            Acs37800ConfigError::NoScaling
+        );
+    }
+}
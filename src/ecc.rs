@@ -0,0 +1,125 @@
+use crate::{Acs37800EepromRaw, Acs37800Register};
+
+/// Data bits of an EEPROM word covered by the ECC, the upper 6 bits hold the ECC itself.
+pub const EEPROM_DATA_MASK: u32 = 0x03ff_ffff;
+
+const ECC_SHIFT: u32 = 26;
+
+/// ## Computes the 6-bit ECC of an EEPROM word.
+///
+/// The 26 data bits are laid out as a Hamming(31, 26) code word: bits 0–4 of the ECC are the
+/// Hamming parity bits and bit 5 is the parity of the whole code word, which allows single-bit
+/// errors to be corrected and double-bit errors to be detected. Bits 26–31 of `word` are ignored.
+///
+/// This layout is a guess that has not been checked against a word dumped from a device, so the
+/// driver never writes or checks ECC bits on its own.
+pub fn eeprom_ecc(word: u32) -> u8 {
+    let data = word & EEPROM_DATA_MASK;
+
+    // Data bits fill the code word positions that are not a power of two, in order. Each parity
+    // bit covers the positions with its bit set, so XOR-ing the positions of all set data bits
+    // yields every parity bit at once.
+    let mut hamming = 0u8;
+    let mut bit = 0;
+    for position in 1u8..=31 {
+        if position.is_power_of_two() {
+            continue;
+        }
+        if data >> bit & 1 != 0 {
+            hamming ^= position;
+        }
+        bit += 1;
+    }
+
+    let overall = (data.count_ones() + hamming.count_ones()) & 1;
+    hamming | (overall as u8) << 5
+}
+
+/// Returns `word` with its ECC bits replaced by the ECC of its data bits.
+pub fn with_eeprom_ecc(word: u32) -> u32 {
+    (word & EEPROM_DATA_MASK) | u32::from(eeprom_ecc(word)) << ECC_SHIFT
+}
+
+/// Whether the ECC stored in `word` matches its data bits.
+pub fn eeprom_ecc_valid(word: u32) -> bool {
+    (word >> ECC_SHIFT) as u8 == eeprom_ecc(word)
+}
+
+impl Acs37800EepromRaw {
+    /// Returns a copy with the ECC of every register recomputed from its data bits.
+    pub fn with_ecc(&self) -> Self {
+        Self {
+            r0b: with_eeprom_ecc(self.r0b.into_bits()).into(),
+            r0c: with_eeprom_ecc(self.r0c.into_bits()).into(),
+            r0d: with_eeprom_ecc(self.r0d.into_bits()).into(),
+            r0e: with_eeprom_ecc(self.r0e.into_bits()).into(),
+            r0f: with_eeprom_ecc(self.r0f.into_bits()).into(),
+        }
+    }
+
+    /// Checks the stored ECC of every register, returning the first EEPROM register that does
+    /// not match.
+    pub fn check_ecc(&self) -> Result<(), Acs37800Register> {
+        self.eeprom_words()
+            .into_iter()
+            .find(|(_, word)| !eeprom_ecc_valid(*word))
+            .map_or(Ok(()), |(register, _)| Err(register))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ecc_of_zero_is_zero() {
+        assert_eq!(eeprom_ecc(0), 0);
+    }
+
+    #[test]
+    fn ecc_of_single_data_bit_sets_its_parity_bits() {
+        // Data bit 0 sits at code word position 3, covered by parity bits 1 and 2
+        assert_eq!(eeprom_ecc(0b1), 0b10_0011);
+        // Data bit 25 sits at code word position 31, covered by every parity bit
+        assert_eq!(eeprom_ecc(1 << 25), 0b01_1111);
+    }
+
+    #[test]
+    fn ecc_ignores_stored_ecc_bits() {
+        assert_eq!(eeprom_ecc(0xfc00_1234), eeprom_ecc(0x0000_1234));
+    }
+
+    #[test]
+    fn with_eeprom_ecc_produces_valid_words() {
+        for word in [0x0000_0001, 0x0123_4567, 0x03ff_ffff, 0xffff_ffff] {
+            assert!(eeprom_ecc_valid(with_eeprom_ecc(word)), "{word:#010x}");
+        }
+    }
+
+    #[test]
+    fn single_bit_flip_is_detected() {
+        let word = with_eeprom_ecc(0x0155_aa55);
+        for bit in 0..32 {
+            assert!(!eeprom_ecc_valid(word ^ 1 << bit), "bit {bit}");
+        }
+    }
+
+    #[test]
+    fn check_ecc_reports_first_corrupt_register() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(0x0000_0001)
+            .r0c(0x0000_0002)
+            .r0d(0x0000_0004)
+            .r0e(0x0000_0008)
+            .r0f(0x0000_0010)
+            .build()
+            .with_ecc();
+        assert_eq!(raw.check_ecc(), Ok(()));
+
+        let corrupt = Acs37800EepromRaw {
+            r0d: (raw.r0d.into_bits() ^ 1 << 13).into(),
+            ..raw
+        };
+        assert_eq!(corrupt.check_ecc(), Err(Acs37800Register::R0D));
+    }
+}
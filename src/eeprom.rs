@@ -1,7 +1,27 @@
 use bitfield_struct::bitfield;
 use bon::Builder;
+use thiserror::Error;
 
-use crate::{Acs37800, Acs37800ReadError};
+use embedded_hal::delay::DelayNs;
+
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+
+use crate::{
+    Acs37800, Acs37800AccessError, Acs37800AccessExt, Acs37800Dio0Function, Acs37800Dio1Function,
+    Acs37800RangeError, Acs37800ReadError, Acs37800Register, Acs37800Scaling, Acs37800Unlocked,
+    Acs37800Variant, Acs37800WriteError, COARSE_GAIN, EEPROM_DATA_MASK, round_to_code,
+    sensitivity_gain,
+};
+
+#[cfg(feature = "async")]
+use crate::{Acs37800AccessAsyncExt, Acs37800Async, Acs37800UnlockedAsync};
+
+/// ## Time allowed for a single EEPROM register write to complete.
+///
+/// Wait applied after every EEPROM write, before the register is read back for verification. This
+/// is a conservative guess rather than a datasheet figure.
+pub const EEPROM_WRITE_TIME_MS: u32 = 100;
 
 /// EEPROM register 0x0B (ACS37800_REGISTER_0B_t)
 /// Bits (LSB0):
@@ -15,17 +35,17 @@ use crate::{Acs37800, Acs37800ReadError};
 #[bitfield(u32, order = Lsb)]
 pub struct Eeprom0bRaw {
     #[bits(9)]
-    qvo_fine: u16,
+    pub qvo_fine: u16,
 
     #[bits(10)]
-    sns_fine: u16,
+    pub sns_fine: u16,
 
     #[bits(3)]
-    crs_sns: u8,
+    pub crs_sns: u8,
 
-    iavgselen: bool,
+    pub iavgselen: bool,
 
-    pavgselen: bool,
+    pub pavgselen: bool,
 
     #[bits(2)]
     _reserved: u8,
@@ -44,13 +64,13 @@ pub struct Eeprom0bRaw {
 #[bitfield(u32, order = Lsb)]
 pub struct Eeprom0cRaw {
     #[bits(7)]
-    rms_avg_1: u8,
+    pub rms_avg_1: u8,
 
     #[bits(10)]
-    rms_avg_2: u16,
+    pub rms_avg_2: u16,
 
     #[bits(8)]
-    vchan_offset_code: u8,
+    pub vchan_offset_code: u8,
 
     _reserved: bool,
 
@@ -74,20 +94,20 @@ pub struct Eeprom0dRaw {
     #[bits(7)]
     _reserved1: u8,
 
-    ichan_del_en: bool,
+    pub ichan_del_en: bool,
 
     reserved2: bool,
 
     #[bits(3)]
-    chan_del_sel: u8,
+    pub chan_del_sel: u8,
 
     _reserved3: bool,
 
     #[bits(8)]
-    fault: u8,
+    pub fault: u8,
 
     #[bits(3)]
-    fltdly: u8,
+    pub fltdly: u8,
 
     #[bits(2)]
     _reserved4: u8,
@@ -112,26 +132,26 @@ pub struct Eeprom0dRaw {
 #[bitfield(u32, order = Lsb)]
 pub struct Eeprom0eRaw {
     #[bits(6)]
-    vevent_cycs: u8,
+    pub vevent_cycs: u8,
 
     #[bits(2)]
     _reserved1: u8,
 
     #[bits(6)]
-    overvreg: u8,
+    pub overvreg: u8,
 
     #[bits(6)]
-    undervreg: u8,
+    pub undervreg: u8,
 
-    delaycnt_sel: bool,
+    pub delaycnt_sel: bool,
 
-    halfcycle_en: bool,
+    pub halfcycle_en: bool,
 
-    squarewave_en: bool,
+    pub squarewave_en: bool,
 
-    zerocrosschansel: bool,
+    pub zerocrosschansel: bool,
 
-    zerocrossedgesel: bool,
+    pub zerocrossedgesel: bool,
 
     _reserved2: bool,
 
@@ -156,20 +176,20 @@ pub struct Eeprom0fRaw {
     _reserved1: u8,
 
     #[bits(7)]
-    i2c_slv_addr: u8,
+    pub i2c_slv_addr: u8,
 
-    i2c_dis_slv_addr: bool,
+    pub i2c_dis_slv_addr: bool,
 
     #[bits(2)]
-    dio_0_sel: u8,
+    pub dio_0_sel: u8,
 
     #[bits(2)]
-    dio_1_sel: u8,
+    pub dio_1_sel: u8,
 
     #[bits(10)]
-    n: u16,
+    pub n: u16,
 
-    bypass_n_en: bool,
+    pub bypass_n_en: bool,
 
     _reserved2: bool,
 
@@ -177,7 +197,7 @@ pub struct Eeprom0fRaw {
     ecc: u8,
 }
 
-#[derive(Builder, Debug)]
+#[derive(Builder, Clone, Copy, Debug)]
 pub struct Acs37800EepromRaw {
     #[builder(into)]
     pub r0b: Eeprom0bRaw,
@@ -191,88 +211,205 @@ pub struct Acs37800EepromRaw {
     pub r0f: Eeprom0fRaw,
 }
 
+impl Acs37800EepromRaw {
+    /// EEPROM register and word pairs, in register order.
+    pub(crate) fn eeprom_words(&self) -> [(Acs37800Register, u32); 5] {
+        [
+            (Acs37800Register::R0B, self.r0b.into_bits()),
+            (Acs37800Register::R0C, self.r0c.into_bits()),
+            (Acs37800Register::R0D, self.r0d.into_bits()),
+            (Acs37800Register::R0E, self.r0e.into_bits()),
+            (Acs37800Register::R0F, self.r0f.into_bits()),
+        ]
+    }
+}
+
+/// Failure while programming the EEPROM.
+///
+/// Registers are programmed in order from 0x0B to 0x0F; every register before the one reported
+/// here was written and verified, the reported register and those after it may hold old or
+/// partially updated content.
+#[derive(Debug, Error)]
+pub enum Acs37800EepromWriteError {
+    #[error(transparent)]
+    Access(#[from] Acs37800AccessError),
+    #[error("Writing EEPROM register {register:?} failed: {source}")]
+    Write {
+        register: Acs37800Register,
+        source: Acs37800WriteError,
+    },
+    #[error("Reading back EEPROM register {register:?} failed: {source}")]
+    ReadBack {
+        register: Acs37800Register,
+        source: Acs37800ReadError,
+    },
+    #[error(
+        "EEPROM register {register:?} failed verification: wrote {expected:#010x}, read {actual:#010x}"
+    )]
+    Verify {
+        register: Acs37800Register,
+        expected: u32,
+        actual: u32,
+    },
+}
+
 pub trait Acs37800EepromExt: Acs37800 {
-    #[cfg(feature = "async")]
+    fn read_eeprom_0b_raw(&mut self) -> Result<Eeprom0bRaw, Acs37800ReadError> {
+        let r0b = Eeprom0bRaw(self.read_reg32(Acs37800Register::R0B)?);
+        Ok(r0b)
+    }
+
+    fn read_eeprom_0c_raw(&mut self) -> Result<Eeprom0cRaw, Acs37800ReadError> {
+        let r0c = Eeprom0cRaw(self.read_reg32(Acs37800Register::R0C)?);
+        Ok(r0c)
+    }
+
+    fn read_eeprom_0d_raw(&mut self) -> Result<Eeprom0dRaw, Acs37800ReadError> {
+        let r0d = Eeprom0dRaw(self.read_reg32(Acs37800Register::R0D)?);
+        Ok(r0d)
+    }
+
+    fn read_eeprom_0e_raw(&mut self) -> Result<Eeprom0eRaw, Acs37800ReadError> {
+        let r0e = Eeprom0eRaw(self.read_reg32(Acs37800Register::R0E)?);
+        Ok(r0e)
+    }
+
+    fn read_eeprom_0f_raw(&mut self) -> Result<Eeprom0fRaw, Acs37800ReadError> {
+        let r0f = Eeprom0fRaw(self.read_reg32(Acs37800Register::R0F)?);
+        Ok(r0f)
+    }
+
+    fn read_eeprom_raw(&mut self) -> Result<Acs37800EepromRaw, Acs37800ReadError> {
+        let r0b = self.read_eeprom_0b_raw()?;
+        let r0c = self.read_eeprom_0c_raw()?;
+        let r0d = self.read_eeprom_0d_raw()?;
+        let r0e = self.read_eeprom_0e_raw()?;
+        let r0f = self.read_eeprom_0f_raw()?;
+        Ok(Acs37800EepromRaw::builder()
+            .r0b(r0b)
+            .r0c(r0c)
+            .r0d(r0d)
+            .r0e(r0e)
+            .r0f(r0f)
+            .build())
+    }
+
+    /// Convenience: read and interpret EEPROM in one go.
+    fn read_eeprom(&mut self) -> Result<Acs37800Eeprom, Acs37800ReadError> {
+        Ok(self.read_eeprom_raw()?.into())
+    }
+
+    /// Unlocks the device, programs and verifies every EEPROM register, then locks it again.
+    ///
+    /// The device is relocked even when programming fails.
+    fn program_eeprom<DELAY: DelayNs>(
+        &mut self,
+        raw: &Acs37800EepromRaw,
+        delay: &mut DELAY,
+    ) -> Result<(), Acs37800EepromWriteError> {
+        let mut unlocked = self.unlock()?;
+        let programmed = unlocked.write_eeprom_raw(raw, delay);
+        let locked = unlocked.lock();
+        programmed?;
+        locked.map_err(Acs37800AccessError::from)?;
+        Ok(())
+    }
+
+    fn read_shadow_0b_raw(&mut self) -> Result<Eeprom0bRaw, Acs37800ReadError> {
+        let r0b = Eeprom0bRaw(self.read_reg32(Acs37800Register::R1B)?);
+        Ok(r0b)
+    }
+
+    fn read_shadow_0c_raw(&mut self) -> Result<Eeprom0cRaw, Acs37800ReadError> {
+        let r0c = Eeprom0cRaw(self.read_reg32(Acs37800Register::R1C)?);
+        Ok(r0c)
+    }
+
+    fn read_shadow_0d_raw(&mut self) -> Result<Eeprom0dRaw, Acs37800ReadError> {
+        let r0d = Eeprom0dRaw(self.read_reg32(Acs37800Register::R1D)?);
+        Ok(r0d)
+    }
+
+    fn read_shadow_0e_raw(&mut self) -> Result<Eeprom0eRaw, Acs37800ReadError> {
+        let r0e = Eeprom0eRaw(self.read_reg32(Acs37800Register::R1E)?);
+        Ok(r0e)
+    }
+
+    fn read_shadow_0f_raw(&mut self) -> Result<Eeprom0fRaw, Acs37800ReadError> {
+        let r0f = Eeprom0fRaw(self.read_reg32(Acs37800Register::R1F)?);
+        Ok(r0f)
+    }
+
+    fn read_shadow_raw(&mut self) -> Result<Acs37800EepromRaw, Acs37800ReadError> {
+        let r0b = self.read_shadow_0b_raw()?;
+        let r0c = self.read_shadow_0c_raw()?;
+        let r0d = self.read_shadow_0d_raw()?;
+        let r0e = self.read_shadow_0e_raw()?;
+        let r0f = self.read_shadow_0f_raw()?;
+        Ok(Acs37800EepromRaw::builder()
+            .r0b(r0b)
+            .r0c(r0c)
+            .r0d(r0d)
+            .r0e(r0e)
+            .r0f(r0f)
+            .build())
+    }
+
+    /// Convenience: read and interpret the live (shadow) configuration in one go.
+    fn read_shadow(&mut self) -> Result<Acs37800Eeprom, Acs37800ReadError> {
+        Ok(self.read_shadow_raw()?.into())
+    }
+}
+
+impl<T: Acs37800 + ?Sized> Acs37800EepromExt for T {}
+
+#[cfg(feature = "async")]
+pub trait Acs37800EepromAsyncExt: Acs37800Async {
     fn read_eeprom_0b_raw(
         &mut self,
     ) -> impl Future<Output = Result<Eeprom0bRaw, Acs37800ReadError>> + '_ {
         async {
-            let r0b = Eeprom0bRaw(self.read_reg32(Acs37800EepromRegister::R0B).await?);
+            let r0b = Eeprom0bRaw(self.read_reg32(Acs37800Register::R0B).await?);
             Ok(r0b)
         }
     }
 
-    #[cfg(not(feature = "async"))]
-    fn read_eeprom_0b_raw(&mut self) -> Result<Eeprom0bRaw, Acs37800ReadError> {
-        let r0b = Eeprom0bRaw(self.read_reg32(Acs37800EepromRegister::R0B)?);
-        Ok(r0b)
-    }
-
-    #[cfg(feature = "async")]
     fn read_eeprom_0c_raw(
         &mut self,
     ) -> impl Future<Output = Result<Eeprom0cRaw, Acs37800ReadError>> + '_ {
         async {
-            let r0c = Eeprom0cRaw(self.read_reg32(Acs37800EepromRegister::R0C).await?);
+            let r0c = Eeprom0cRaw(self.read_reg32(Acs37800Register::R0C).await?);
             Ok(r0c)
         }
     }
 
-    #[cfg(not(feature = "async"))]
-    fn read_eeprom_0c_raw(&mut self) -> Result<Eeprom0cRaw, Acs37800ReadError> {
-        let r0c = Eeprom0cRaw(self.read_reg32(Acs37800EepromRegister::R0C)?);
-        Ok(r0c)
-    }
-
-    #[cfg(feature = "async")]
     fn read_eeprom_0d_raw(
         &mut self,
     ) -> impl Future<Output = Result<Eeprom0dRaw, Acs37800ReadError>> + '_ {
         async {
-            let r0d = Eeprom0dRaw(self.read_reg32(Acs37800EepromRegister::R0D).await?);
+            let r0d = Eeprom0dRaw(self.read_reg32(Acs37800Register::R0D).await?);
             Ok(r0d)
         }
     }
 
-    #[cfg(not(feature = "async"))]
-    fn read_eeprom_0d_raw(&mut self) -> Result<Eeprom0dRaw, Acs37800ReadError> {
-        let r0d = Eeprom0dRaw(self.read_reg32(Acs37800EepromRegister::R0D)?);
-        Ok(r0d)
-    }
-
-    #[cfg(feature = "async")]
     fn read_eeprom_0e_raw(
         &mut self,
     ) -> impl Future<Output = Result<Eeprom0eRaw, Acs37800ReadError>> + '_ {
         async {
-            let r0e = Eeprom0eRaw(self.read_reg32(Acs37800EepromRegister::R0E).await?);
+            let r0e = Eeprom0eRaw(self.read_reg32(Acs37800Register::R0E).await?);
             Ok(r0e)
         }
     }
 
-    #[cfg(not(feature = "async"))]
-    fn read_eeprom_0e_raw(&mut self) -> Result<Eeprom0eRaw, Acs37800ReadError> {
-        let r0e = Eeprom0eRaw(self.read_reg32(Acs37800EepromRegister::R0E)?);
-        Ok(r0e)
-    }
-
-    #[cfg(feature = "async")]
     fn read_eeprom_0f_raw(
         &mut self,
     ) -> impl Future<Output = Result<Eeprom0fRaw, Acs37800ReadError>> + '_ {
         async {
-            let r0f = Eeprom0fRaw(self.read_reg32(Acs37800EepromRegister::R0F).await?);
+            let r0f = Eeprom0fRaw(self.read_reg32(Acs37800Register::R0F).await?);
             Ok(r0f)
         }
     }
 
-    #[cfg(not(feature = "async"))]
-    fn read_eeprom_0f_raw(&mut self) -> Result<Eeprom0fRaw, Acs37800ReadError> {
-        let r0f = Eeprom0fRaw(self.read_reg32(Acs37800EepromRegister::R0F)?);
-        Ok(r0f)
-    }
-
-    #[cfg(feature = "async")]
     fn read_eeprom_raw(
         &mut self,
     ) -> impl Future<Output = Result<Acs37800EepromRaw, Acs37800ReadError>> + '_ {
@@ -292,37 +429,253 @@ pub trait Acs37800EepromExt: Acs37800 {
         }
     }
 
-    #[cfg(not(feature = "async"))]
-    fn read_eeprom_raw(&mut self) -> Result<Acs37800EepromRaw, Acs37800ReadError> {
-        let r0b = self.read_eeprom_0b_raw()?;
-        let r0c = self.read_eeprom_0c_raw()?;
-        let r0d = self.read_eeprom_0d_raw()?;
-        let r0e = self.read_eeprom_0e_raw()?;
-        let r0f = self.read_eeprom_0f_raw()?;
-        Ok(Acs37800EepromRaw::builder()
-            .r0b(r0b)
-            .r0c(r0c)
-            .r0d(r0d)
-            .r0e(r0e)
-            .r0f(r0f)
-            .build())
-    }
-
     /// Convenience: read and interpret EEPROM in one go.
-    #[cfg(feature = "async")]
     fn read_eeprom(
         &mut self,
     ) -> impl Future<Output = Result<Acs37800Eeprom, Acs37800ReadError>> + '_ {
         async { Ok(self.read_eeprom_raw().await?.into()) }
     }
 
-    #[cfg(not(feature = "async"))]
-    fn read_eeprom(&mut self) -> Result<Acs37800Eeprom, Acs37800ReadError> {
-        Ok(self.read_eeprom_raw()?.into())
+    /// Unlocks the device, programs and verifies every EEPROM register, then locks it again.
+    ///
+    /// The device is relocked even when programming fails.
+    fn program_eeprom<'a, DELAY: AsyncDelayNs>(
+        &'a mut self,
+        raw: &'a Acs37800EepromRaw,
+        delay: &'a mut DELAY,
+    ) -> impl Future<Output = Result<(), Acs37800EepromWriteError>> + 'a {
+        async move {
+            let mut unlocked = self.unlock().await?;
+            let programmed = unlocked.write_eeprom_raw(raw, delay).await;
+            let locked = unlocked.lock().await;
+            programmed?;
+            locked.map_err(Acs37800AccessError::from)?;
+            Ok(())
+        }
+    }
+
+    fn read_shadow_0b_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Eeprom0bRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r0b = Eeprom0bRaw(self.read_reg32(Acs37800Register::R1B).await?);
+            Ok(r0b)
+        }
+    }
+
+    fn read_shadow_0c_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Eeprom0cRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r0c = Eeprom0cRaw(self.read_reg32(Acs37800Register::R1C).await?);
+            Ok(r0c)
+        }
+    }
+
+    fn read_shadow_0d_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Eeprom0dRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r0d = Eeprom0dRaw(self.read_reg32(Acs37800Register::R1D).await?);
+            Ok(r0d)
+        }
+    }
+
+    fn read_shadow_0e_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Eeprom0eRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r0e = Eeprom0eRaw(self.read_reg32(Acs37800Register::R1E).await?);
+            Ok(r0e)
+        }
+    }
+
+    fn read_shadow_0f_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Eeprom0fRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r0f = Eeprom0fRaw(self.read_reg32(Acs37800Register::R1F).await?);
+            Ok(r0f)
+        }
+    }
+
+    fn read_shadow_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800EepromRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r0b = self.read_shadow_0b_raw().await?;
+            let r0c = self.read_shadow_0c_raw().await?;
+            let r0d = self.read_shadow_0d_raw().await?;
+            let r0e = self.read_shadow_0e_raw().await?;
+            let r0f = self.read_shadow_0f_raw().await?;
+            Ok(Acs37800EepromRaw::builder()
+                .r0b(r0b)
+                .r0c(r0c)
+                .r0d(r0d)
+                .r0e(r0e)
+                .r0f(r0f)
+                .build())
+        }
+    }
+
+    /// Convenience: read and interpret the live (shadow) configuration in one go.
+    fn read_shadow(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800Eeprom, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_shadow_raw().await?.into()) }
     }
 }
 
-impl<T: Acs37800 + ?Sized> Acs37800EepromExt for T {}
+#[cfg(feature = "async")]
+impl<T: Acs37800Async + ?Sized> Acs37800EepromAsyncExt for T {}
+
+/// Shadow register writes, these take effect immediately and are lost on power-down.
+impl<D: Acs37800 + ?Sized> Acs37800Unlocked<'_, D> {
+    pub fn write_shadow_0b_raw(&mut self, r0b: Eeprom0bRaw) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1B, r0b.into_bits())
+    }
+
+    pub fn write_shadow_0c_raw(&mut self, r0c: Eeprom0cRaw) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1C, r0c.into_bits())
+    }
+
+    pub fn write_shadow_0d_raw(&mut self, r0d: Eeprom0dRaw) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1D, r0d.into_bits())
+    }
+
+    pub fn write_shadow_0e_raw(&mut self, r0e: Eeprom0eRaw) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1E, r0e.into_bits())
+    }
+
+    pub fn write_shadow_0f_raw(&mut self, r0f: Eeprom0fRaw) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1F, r0f.into_bits())
+    }
+
+    pub fn write_shadow_raw(&mut self, raw: &Acs37800EepromRaw) -> Result<(), Acs37800WriteError> {
+        self.write_shadow_0b_raw(raw.r0b)?;
+        self.write_shadow_0c_raw(raw.r0c)?;
+        self.write_shadow_0d_raw(raw.r0d)?;
+        self.write_shadow_0e_raw(raw.r0e)?;
+        self.write_shadow_0f_raw(raw.r0f)
+    }
+
+    /// Programs every EEPROM register, waiting [`EEPROM_WRITE_TIME_MS`] after each write and
+    /// reading it back for verification before moving on to the next one.
+    ///
+    /// Words are written as given, verification compares the data bits only since the device may
+    /// store its own ECC.
+    pub fn write_eeprom_raw<DELAY: DelayNs>(
+        &mut self,
+        raw: &Acs37800EepromRaw,
+        delay: &mut DELAY,
+    ) -> Result<(), Acs37800EepromWriteError> {
+        for (register, word) in raw.eeprom_words() {
+            self.write_reg32(register, word)
+                .map_err(|source| Acs37800EepromWriteError::Write { register, source })?;
+            delay.delay_ms(EEPROM_WRITE_TIME_MS);
+            let actual = self
+                .read_reg32(register)
+                .map_err(|source| Acs37800EepromWriteError::ReadBack { register, source })?;
+            verify_eeprom_word(register, word, actual)?;
+        }
+        Ok(())
+    }
+}
+
+/// Shadow register writes, these take effect immediately and are lost on power-down.
+#[cfg(feature = "async")]
+impl<D: Acs37800Async + ?Sized> Acs37800UnlockedAsync<'_, D> {
+    pub async fn write_shadow_0b_raw(
+        &mut self,
+        r0b: Eeprom0bRaw,
+    ) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1B, r0b.into_bits())
+            .await
+    }
+
+    pub async fn write_shadow_0c_raw(
+        &mut self,
+        r0c: Eeprom0cRaw,
+    ) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1C, r0c.into_bits())
+            .await
+    }
+
+    pub async fn write_shadow_0d_raw(
+        &mut self,
+        r0d: Eeprom0dRaw,
+    ) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1D, r0d.into_bits())
+            .await
+    }
+
+    pub async fn write_shadow_0e_raw(
+        &mut self,
+        r0e: Eeprom0eRaw,
+    ) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1E, r0e.into_bits())
+            .await
+    }
+
+    pub async fn write_shadow_0f_raw(
+        &mut self,
+        r0f: Eeprom0fRaw,
+    ) -> Result<(), Acs37800WriteError> {
+        self.write_reg32(Acs37800Register::R1F, r0f.into_bits())
+            .await
+    }
+
+    pub async fn write_shadow_raw(
+        &mut self,
+        raw: &Acs37800EepromRaw,
+    ) -> Result<(), Acs37800WriteError> {
+        self.write_shadow_0b_raw(raw.r0b).await?;
+        self.write_shadow_0c_raw(raw.r0c).await?;
+        self.write_shadow_0d_raw(raw.r0d).await?;
+        self.write_shadow_0e_raw(raw.r0e).await?;
+        self.write_shadow_0f_raw(raw.r0f).await
+    }
+
+    /// Programs every EEPROM register, waiting [`EEPROM_WRITE_TIME_MS`] after each write and
+    /// reading it back for verification before moving on to the next one.
+    ///
+    /// Words are written as given, verification compares the data bits only since the device may
+    /// store its own ECC.
+    pub async fn write_eeprom_raw<DELAY: AsyncDelayNs>(
+        &mut self,
+        raw: &Acs37800EepromRaw,
+        delay: &mut DELAY,
+    ) -> Result<(), Acs37800EepromWriteError> {
+        for (register, word) in raw.eeprom_words() {
+            self.write_reg32(register, word)
+                .await
+                .map_err(|source| Acs37800EepromWriteError::Write { register, source })?;
+            delay.delay_ms(EEPROM_WRITE_TIME_MS).await;
+            let actual = self
+                .read_reg32(register)
+                .await
+                .map_err(|source| Acs37800EepromWriteError::ReadBack { register, source })?;
+            verify_eeprom_word(register, word, actual)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares the data bits of a programmed word, the ECC bits are left to the device.
+fn verify_eeprom_word(
+    register: Acs37800Register,
+    expected: u32,
+    actual: u32,
+) -> Result<(), Acs37800EepromWriteError> {
+    if expected & EEPROM_DATA_MASK != actual & EEPROM_DATA_MASK {
+        return Err(Acs37800EepromWriteError::Verify {
+            register,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
 
 /// Higher-level interpreted view of the EEPROM content.
 ///
@@ -339,14 +692,17 @@ pub struct Acs37800Eeprom {
     pub qvo_fine_icodes_offset: i32,
     /// SNS_FINE in register codes, sign-extended (-512..=511).
     pub sns_fine_codes: i16,
-    /// Coarse sensitivity trim setting (datasheet-specific interpretation).
+    /// Coarse sensitivity setting (`crs_sns`, 0..=7).
     pub crs_sns: u8,
+    /// Current channel gain selected by `crs_sns`.
+    pub coarse_gain: f32,
+    /// Current channel gain with the `sns_fine` trim applied.
+    pub sensitivity_gain: f32,
     pub iavgsel_enabled: bool,
     pub pavgsel_enabled: bool,
 
     // RMS averaging & voltage offset (register 0x0C)
-    /// Raw averaging config fields (you can turn these into cycles / time
-    /// based on the datasheet’s equations and your line frequency).
+    /// Averager lengths, see [`Acs37800Eeprom::rms_averaging`].
     pub rms_avg_1: u8,
     pub rms_avg_2: u16,
     /// Signed voltage channel offset code.
@@ -355,9 +711,9 @@ pub struct Acs37800Eeprom {
     // Overcurrent / fault (register 0x0D)
     pub ichan_delay_enabled: bool,
     pub chan_delay_sel: u8,
-    /// Fault threshold code (maps into IRMS threshold per datasheet).
+    /// Fault threshold code, see [`Acs37800Eeprom::fault_threshold_amps`].
     pub fault_threshold_codes: u8,
-    /// Fault delay setting (raw).
+    /// Fault delay setting, see [`Acs37800Eeprom::fault_delay_us`].
     pub fault_delay_setting: u8,
 
     // Voltage events, UV/OV thresholds, zero-crossing (register 0x0E)
@@ -374,10 +730,10 @@ pub struct Acs37800Eeprom {
     // I2C, DIO, N (register 0x0F)
     /// 7-bit I2C address.
     pub i2c_address_7bit: u8,
-    /// If true, the EEPROM address is disabled (device uses default address).
+    /// If true, DIO-based address selection is disabled and the device uses `i2c_address_7bit`.
     pub i2c_address_disabled: bool,
-    pub dio0_sel_raw: u8,
-    pub dio1_sel_raw: u8,
+    pub dio0_function: Acs37800Dio0Function,
+    pub dio1_function: Acs37800Dio1Function,
     /// N cycles for certain measurements (see datasheet, and BYPASS_N_EN).
     pub n_cycles: u16,
     pub bypass_n_en: bool,
@@ -410,6 +766,8 @@ impl From<Acs37800EepromRaw> for Acs37800Eeprom {
             qvo_fine_icodes_offset,
             sns_fine_codes,
             crs_sns: r0b.crs_sns(),
+            coarse_gain: COARSE_GAIN[usize::from(r0b.crs_sns())],
+            sensitivity_gain: sensitivity_gain(r0b.crs_sns(), sns_fine_codes),
             iavgsel_enabled: r0b.iavgselen(),
             pavgsel_enabled: r0b.pavgselen(),
 
@@ -437,86 +795,182 @@ impl From<Acs37800EepromRaw> for Acs37800Eeprom {
             // 0x0F
             i2c_address_7bit: r0f.i2c_slv_addr(),
             i2c_address_disabled: r0f.i2c_dis_slv_addr(),
-            dio0_sel_raw: r0f.dio_0_sel(),
-            dio1_sel_raw: r0f.dio_1_sel(),
+            dio0_function: r0f.dio0_function(),
+            dio1_function: r0f.dio1_function(),
             n_cycles: r0f.n(),
             bypass_n_en: r0f.bypass_n_en(),
         }
     }
 }
 
-/// Helper: sign-extend a N-bit unsigned value into i16.
-fn sign_extend(val: u16, bits: u8) -> i16 {
-    let mask = (1u16 << bits) - 1;
-    let sign_bit = 1u16 << (bits - 1);
-    let v = val & mask;
-    if v & sign_bit != 0 {
-        // fill upper bits with 1s
-        (v as i16) | !((mask) as i16)
-    } else {
-        v as i16
+/// Interpreted EEPROM value that cannot be packed back into its register field.
+#[derive(Debug, Error, Clone, Copy, PartialEq)]
+pub enum Acs37800EepromEncodeError {
+    #[error(transparent)]
+    Range(#[from] Acs37800RangeError),
+    #[error("zerocross_pulse_width_us of {0} is neither 32 nor 256")]
+    ZerocrossPulseWidth(u32),
+}
+
+/// Packs the interpreted view into fresh register words.
+///
+/// Reserved bits and ECC are left zero, use [`Acs37800Eeprom::apply_to`] to write an edited view
+/// back over the words it was read from.
+impl TryFrom<Acs37800Eeprom> for Acs37800EepromRaw {
+    type Error = Acs37800EepromEncodeError;
+
+    fn try_from(eeprom: Acs37800Eeprom) -> Result<Self, Self::Error> {
+        let mut raw = Acs37800EepromRaw {
+            r0b: Eeprom0bRaw::new(),
+            r0c: Eeprom0cRaw::new(),
+            r0d: Eeprom0dRaw::new(),
+            r0e: Eeprom0eRaw::new(),
+            r0f: Eeprom0fRaw::new(),
+        };
+        eeprom.apply_to(&mut raw)?;
+        Ok(raw)
     }
 }
 
-#[repr(u8)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-pub enum Acs37800EepromRegister {
-    R0B = 0x0b,
-    R0C = 0x0c,
-    R0D = 0x0d,
-    R0E = 0x0e,
-    R0F = 0x0f,
+impl Acs37800Eeprom {
+    /// Packs the interpreted fields onto `raw`, leaving reserved bits and ECC as they are.
+    ///
+    /// The derived fields (`qvo_fine_icodes_offset`, `coarse_gain`, `sensitivity_gain`) are
+    /// ignored. Fails on values that do not fit their bitfield, `raw` is only
+    /// updated when every field fits.
+    pub fn apply_to(&self, raw: &mut Acs37800EepromRaw) -> Result<(), Acs37800EepromEncodeError> {
+        let delaycnt_sel = match self.zerocross_pulse_width_us {
+            32 => false,
+            256 => true,
+            other => return Err(Acs37800EepromEncodeError::ZerocrossPulseWidth(other)),
+        };
+
+        let r0b = raw
+            .r0b
+            .with_qvo_fine(signed_field("qvo_fine_codes", self.qvo_fine_codes, 9)?)
+            .with_sns_fine(signed_field("sns_fine_codes", self.sns_fine_codes, 10)?)
+            .with_crs_sns(unsigned_field("crs_sns", self.crs_sns.into(), 3)? as u8)
+            .with_iavgselen(self.iavgsel_enabled)
+            .with_pavgselen(self.pavgsel_enabled);
+
+        let r0c = raw
+            .r0c
+            .with_rms_avg_1(unsigned_field("rms_avg_1", self.rms_avg_1.into(), 7)? as u8)
+            .with_rms_avg_2(unsigned_field("rms_avg_2", self.rms_avg_2, 10)?)
+            .with_vchan_offset_code(self.vchan_offset_codes as u8);
+
+        let r0d = raw
+            .r0d
+            .with_ichan_del_en(self.ichan_delay_enabled)
+            .with_chan_del_sel(
+                unsigned_field("chan_delay_sel", self.chan_delay_sel.into(), 3)? as u8,
+            )
+            .with_fault(self.fault_threshold_codes)
+            .with_fltdly(
+                unsigned_field("fault_delay_setting", self.fault_delay_setting.into(), 3)? as u8,
+            );
+
+        let r0e = raw
+            .r0e
+            .with_vevent_cycs(unsigned_field("vevent_cycles", self.vevent_cycles.into(), 6)? as u8)
+            .with_overvreg(unsigned_field(
+                "overvoltage_threshold_codes",
+                self.overvoltage_threshold_codes.into(),
+                6,
+            )? as u8)
+            .with_undervreg(unsigned_field(
+                "undervoltage_threshold_codes",
+                self.undervoltage_threshold_codes.into(),
+                6,
+            )? as u8)
+            .with_delaycnt_sel(delaycnt_sel)
+            .with_halfcycle_en(self.halfcycle_en)
+            .with_squarewave_en(self.squarewave_en)
+            .with_zerocrosschansel(self.zerocross_current_channel)
+            .with_zerocrossedgesel(self.zerocross_rising_edge);
+
+        let r0f = raw
+            .r0f
+            .with_i2c_slv_addr(
+                unsigned_field("i2c_address_7bit", self.i2c_address_7bit.into(), 7)? as u8,
+            )
+            .with_i2c_dis_slv_addr(self.i2c_address_disabled)
+            .with_dio0_function(self.dio0_function)
+            .with_dio1_function(self.dio1_function)
+            .with_n(unsigned_field("n_cycles", self.n_cycles, 10)?)
+            .with_bypass_n_en(self.bypass_n_en);
+
+        *raw = Acs37800EepromRaw {
+            r0b,
+            r0c,
+            r0d,
+            r0e,
+            r0f,
+        };
+        Ok(())
+    }
 }
 
-#[cfg(test)]
-mod test_support {
-    use std::collections::HashMap;
+/// Checks that `value` fits an unsigned bitfield of `bits` bits.
+fn unsigned_field(field: &'static str, value: u16, bits: u8) -> Result<u16, Acs37800RangeError> {
+    let max = (1u16 << bits) - 1;
+    Acs37800RangeError::check(field, f32::from(value), 0.0, f32::from(max))?;
+    Ok(value)
+}
 
-    use super::*;
+/// Checks that `value` fits a two's complement bitfield of `bits` bits and truncates it to the
+/// field width, the inverse of [`sign_extend`].
+fn signed_field(field: &'static str, value: i16, bits: u8) -> Result<u16, Acs37800RangeError> {
+    let max = (1i16 << (bits - 1)) - 1;
+    let min = -max - 1;
+    Acs37800RangeError::check(field, f32::from(value), f32::from(min), f32::from(max))?;
+    Ok(value as u16 & ((1u16 << bits) - 1))
+}
 
-    #[derive(Default)]
-    pub(super) struct MockDevice {
-        regs: HashMap<Acs37800EepromRegister, u32>,
-        fail_on: Option<Acs37800EepromRegister>,
+impl Acs37800Eeprom {
+    /// Overcurrent fault threshold in amps for the given part variant.
+    pub fn fault_threshold_amps(&self, variant: Acs37800Variant) -> f32 {
+        variant.fault_threshold_amps(self.fault_threshold_codes)
     }
 
-    impl MockDevice {
-        pub(super) fn set_reg(&mut self, reg: Acs37800EepromRegister, value: u32) {
-            self.regs.insert(reg, value);
-        }
-
-        pub(super) fn with_failure(reg: Acs37800EepromRegister) -> Self {
-            Self {
-                regs: HashMap::new(),
-                fail_on: Some(reg),
-            }
-        }
+    /// Overvoltage threshold in RMS volts for the given board scaling.
+    pub fn overvoltage_threshold_volts(&self, scaling: &Acs37800Scaling) -> f32 {
+        scaling.voltage_threshold_volts(self.overvoltage_threshold_codes)
+    }
 
-        fn read_word(&mut self, reg: Acs37800EepromRegister) -> Result<u32, Acs37800ReadError> {
-            if self.fail_on == Some(reg) {
-                return Err(bus_error());
-            }
-            self.regs.get(&reg).copied().ok_or_else(bus_error)
-        }
+    /// Undervoltage threshold in RMS volts for the given board scaling.
+    pub fn undervoltage_threshold_volts(&self, scaling: &Acs37800Scaling) -> f32 {
+        scaling.voltage_threshold_volts(self.undervoltage_threshold_codes)
     }
 
-    #[cfg(not(feature = "async"))]
-    impl Acs37800 for MockDevice {
-        fn read_reg32(&mut self, reg: Acs37800EepromRegister) -> Result<u32, Acs37800ReadError> {
-            self.read_word(reg)
-        }
+    /// Time the voltage must stay beyond the OV/UV thresholds before the event is flagged, in
+    /// milliseconds at the given line frequency.
+    pub fn vevent_duration_ms(&self, line_frequency_hz: f32) -> f32 {
+        f32::from(self.vevent_cycles) * 1000.0 / line_frequency_hz
     }
 
-    #[cfg(feature = "async")]
-    impl Acs37800 for MockDevice {
-        fn read_reg32(
-            &mut self,
-            reg: Acs37800EepromRegister,
-        ) -> impl Future<Output = Result<u32, Acs37800ReadError>> {
-            let result = self.read_word(reg);
-            async move { result }
-        }
+    /// Converts an OV/UV event duration in milliseconds to the nearest `vevent_cycs` value at the
+    /// given line frequency.
+    pub fn vevent_cycles_from_ms(
+        duration_ms: f32,
+        line_frequency_hz: f32,
+    ) -> Result<u8, Acs37800RangeError> {
+        let cycles = duration_ms * line_frequency_hz / 1000.0;
+        let cycles = Acs37800RangeError::check("vevent cycles", cycles, 0.0, 63.0)?;
+        Ok(round_to_code(cycles) as u8)
     }
+}
+
+/// Helper: sign-extend a N-bit unsigned value (1..=16 bits) into i16.
+pub(crate) fn sign_extend(val: u16, bits: u8) -> i16 {
+    // move the sign bit to the top, then shift back arithmetically to fill the upper bits
+    let shift = 16 - u32::from(bits);
+    ((val << shift) as i16) >> shift
+}
+
+#[cfg(test)]
+mod test_support {
+    use crate::with_eeprom_ecc;
 
     pub(super) fn pack_r0b(
         qvo_fine: u16,
@@ -531,7 +985,7 @@ mod test_support {
         value |= ((crs_sns as u32) & 0x7) << 19;
         value |= bit(iavg) << 22;
         value |= bit(pavg) << 23;
-        value
+        with_eeprom_ecc(value)
     }
 
     pub(super) fn pack_r0c(rms_avg_1: u8, rms_avg_2: u16, vchan_offset: u8) -> u32 {
@@ -539,7 +993,7 @@ mod test_support {
         value |= (rms_avg_1 as u32) & 0x7f;
         value |= ((rms_avg_2 as u32) & 0x3ff) << 7;
         value |= ((vchan_offset as u32) & 0xff) << 17;
-        value
+        with_eeprom_ecc(value)
     }
 
     pub(super) fn pack_r0d(ichan_en: bool, chan_sel: u8, fault: u8, fltdly: u8) -> u32 {
@@ -548,7 +1002,7 @@ mod test_support {
         value |= ((chan_sel as u32) & 0x7) << 9;
         value |= ((fault as u32) & 0xff) << 13;
         value |= ((fltdly as u32) & 0x7) << 21;
-        value
+        with_eeprom_ecc(value)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -571,7 +1025,7 @@ mod test_support {
         value |= bit(squarewave) << 22;
         value |= bit(zerocross_channel) << 23;
         value |= bit(zerocross_edge) << 24;
-        value
+        with_eeprom_ecc(value)
     }
 
     pub(super) fn pack_r0f(
@@ -589,18 +1043,7 @@ mod test_support {
         value |= ((dio1 as u32) & 0x3) << 12;
         value |= ((n_cycles as u32) & 0x3ff) << 14;
         value |= bit(bypass) << 24;
-        value
-    }
-
-    pub(super) fn bus_error() -> Acs37800ReadError {
-        #[cfg(feature = "std")]
-        {
-            Acs37800ReadError::Io("mock".into())
-        }
-        #[cfg(not(feature = "std"))]
-        {
-            Acs37800ReadError::Io
-        }
+        with_eeprom_ecc(value)
     }
 
     fn bit(flag: bool) -> u32 {
@@ -608,9 +1051,13 @@ mod test_support {
     }
 }
 
-#[cfg(all(test, not(feature = "async")))]
+#[cfg(test)]
 mod tests {
-    use crate::{Acs37800EepromExt, test::assert_is_bus_error};
+    use crate::{
+        Acs37800AccessExt, Acs37800EepromExt,
+        test::{MockDevice, assert_is_bus_error},
+    };
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, NoopDelay, Transaction as DelayTransaction};
 
     use super::test_support::*;
     use super::*;
@@ -619,20 +1066,17 @@ mod tests {
     fn read_eeprom_raw_gathers_all_registers() {
         let mut mock = MockDevice::default();
         mock.set_reg(
-            Acs37800EepromRegister::R0B,
+            Acs37800Register::R0B,
             pack_r0b(0x101, 0x155, 0b011, true, false),
         );
-        mock.set_reg(Acs37800EepromRegister::R0C, pack_r0c(0x45, 0x155, 0x3A));
-        mock.set_reg(
-            Acs37800EepromRegister::R0D,
-            pack_r0d(true, 0b010, 0x5A, 0b111),
-        );
+        mock.set_reg(Acs37800Register::R0C, pack_r0c(0x45, 0x155, 0x3A));
+        mock.set_reg(Acs37800Register::R0D, pack_r0d(true, 0b010, 0x5A, 0b111));
         mock.set_reg(
-            Acs37800EepromRegister::R0E,
+            Acs37800Register::R0E,
             pack_r0e(0x12, 0x21, 0x11, false, true, false, true, false),
         );
         mock.set_reg(
-            Acs37800EepromRegister::R0F,
+            Acs37800Register::R0F,
             pack_r0f(0x63, false, 0b01, 0b10, 0x12C, true),
         );
 
@@ -649,20 +1093,17 @@ mod tests {
     fn read_eeprom_interprets_signed_and_flags() {
         let mut mock = MockDevice::default();
         mock.set_reg(
-            Acs37800EepromRegister::R0B,
+            Acs37800Register::R0B,
             pack_r0b(0x1A5, 0x2D3, 0b010, true, true),
         );
-        mock.set_reg(Acs37800EepromRegister::R0C, pack_r0c(0x40, 0x155, 0xF6));
-        mock.set_reg(
-            Acs37800EepromRegister::R0D,
-            pack_r0d(true, 0b101, 0xAA, 0b110),
-        );
+        mock.set_reg(Acs37800Register::R0C, pack_r0c(0x40, 0x155, 0xF6));
+        mock.set_reg(Acs37800Register::R0D, pack_r0d(true, 0b101, 0xAA, 0b110));
         mock.set_reg(
-            Acs37800EepromRegister::R0E,
+            Acs37800Register::R0E,
             pack_r0e(0x17, 0x2A, 0x18, true, true, false, true, false),
         );
         mock.set_reg(
-            Acs37800EepromRegister::R0F,
+            Acs37800Register::R0F,
             pack_r0f(0x52, true, 0b10, 0b01, 0x1F3, true),
         );
 
@@ -672,6 +1113,7 @@ mod tests {
         assert_eq!(eeprom.sns_fine_codes, -301);
         assert_eq!(eeprom.vchan_offset_codes, -10);
         assert_eq!(eeprom.crs_sns, 0b010);
+        assert_eq!(eeprom.coarse_gain, 3.0);
         assert!(eeprom.iavgsel_enabled);
         assert!(eeprom.pavgsel_enabled);
         assert_eq!(eeprom.rms_avg_1, 0x40);
@@ -690,46 +1132,197 @@ mod tests {
         assert!(!eeprom.zerocross_rising_edge);
         assert_eq!(eeprom.i2c_address_7bit, 0x52);
         assert!(eeprom.i2c_address_disabled);
-        assert_eq!(eeprom.dio0_sel_raw, 0b10);
-        assert_eq!(eeprom.dio1_sel_raw, 0b01);
+        assert_eq!(eeprom.dio0_function, Acs37800Dio0Function::Undervoltage);
+        assert_eq!(eeprom.dio1_function, Acs37800Dio1Function::Undervoltage);
         assert_eq!(eeprom.n_cycles, 0x1F3);
         assert!(eeprom.bypass_n_en);
     }
 
     #[test]
     fn read_eeprom_propagates_errors() {
-        let mut mock = MockDevice::with_failure(Acs37800EepromRegister::R0C);
-        mock.set_reg(Acs37800EepromRegister::R0B, pack_r0b(0, 0, 0, false, false));
+        let mut mock = MockDevice::with_failure(Acs37800Register::R0C);
+        mock.set_reg(Acs37800Register::R0B, pack_r0b(0, 0, 0, false, false));
         let err = mock.read_eeprom_raw().unwrap_err();
         assert_is_bus_error(&err);
     }
+
+    #[test]
+    fn read_shadow_reads_volatile_copies() {
+        let mut mock = MockDevice::default();
+        mock.set_reg(
+            Acs37800Register::R1B,
+            pack_r0b(0x1A5, 0x2D3, 0b010, true, true),
+        );
+        mock.set_reg(Acs37800Register::R1C, pack_r0c(0x40, 0x155, 0xF6));
+        mock.set_reg(Acs37800Register::R1D, pack_r0d(true, 0b101, 0xAA, 0b110));
+        mock.set_reg(
+            Acs37800Register::R1E,
+            pack_r0e(0x17, 0x2A, 0x18, true, true, false, true, false),
+        );
+        mock.set_reg(
+            Acs37800Register::R1F,
+            pack_r0f(0x52, true, 0b10, 0b01, 0x1F3, true),
+        );
+
+        let shadow = mock.read_shadow().expect("parsed shadow");
+        assert_eq!(shadow.qvo_fine_codes, -91);
+        assert_eq!(shadow.vchan_offset_codes, -10);
+        assert_eq!(shadow.fault_threshold_codes, 0xAA);
+        assert_eq!(shadow.overvoltage_threshold_codes, 0x2A);
+        assert_eq!(shadow.n_cycles, 0x1F3);
+    }
+
+    #[test]
+    fn write_shadow_raw_targets_shadow_registers() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(pack_r0b(0x101, 0x155, 0b011, true, false))
+            .r0c(pack_r0c(0x45, 0x155, 0x3A))
+            .r0d(pack_r0d(true, 0b010, 0x5A, 0b111))
+            .r0e(pack_r0e(0x12, 0x21, 0x11, false, true, false, true, false))
+            .r0f(pack_r0f(0x63, false, 0b01, 0b10, 0x12C, true))
+            .build();
+        let mut mock = MockDevice::default();
+
+        let mut unlocked = mock.unlock().expect("unlocked");
+        unlocked.write_shadow_raw(&raw).expect("shadow written");
+        unlocked.lock().expect("locked");
+
+        let written: Vec<_> = mock.writes[1..6].iter().map(|(reg, _)| *reg).collect();
+        assert_eq!(
+            written,
+            [
+                Acs37800Register::R1B,
+                Acs37800Register::R1C,
+                Acs37800Register::R1D,
+                Acs37800Register::R1E,
+                Acs37800Register::R1F,
+            ]
+        );
+        let shadow = mock.read_shadow_raw().expect("shadow");
+        assert_eq!(shadow.r0c.rms_avg_1(), 0x45);
+        assert_eq!(shadow.r0f.n(), 0x12C);
+    }
+
+    #[test]
+    fn program_eeprom_writes_verifies_and_relocks() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(pack_r0b(0x101, 0x155, 0b011, true, false))
+            .r0c(pack_r0c(0x45, 0x155, 0x3A))
+            .r0d(pack_r0d(true, 0b010, 0x5A, 0b111))
+            .r0e(pack_r0e(0x12, 0x21, 0x11, false, true, false, true, false))
+            .r0f(pack_r0f(0x63, false, 0b01, 0b10, 0x12C, true))
+            .build();
+        let mut mock = MockDevice::default();
+        let mut delay =
+            CheckedDelay::new(&vec![DelayTransaction::delay_ms(EEPROM_WRITE_TIME_MS); 5]);
+
+        mock.program_eeprom(&raw, &mut delay).expect("programmed");
+
+        let written: Vec<_> = mock.writes.iter().map(|(reg, _)| *reg).collect();
+        assert_eq!(
+            written,
+            [
+                Acs37800Register::R2F,
+                Acs37800Register::R0B,
+                Acs37800Register::R0C,
+                Acs37800Register::R0D,
+                Acs37800Register::R0E,
+                Acs37800Register::R0F,
+                Acs37800Register::R2F,
+            ]
+        );
+        assert!(!mock.is_unlocked().expect("status"));
+        let eeprom = mock.read_eeprom_raw().expect("eeprom");
+        assert_eq!(eeprom.r0d.fault(), 0x5A);
+        delay.done();
+    }
+
+    #[test]
+    fn program_eeprom_leaves_ecc_to_the_device() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(0x0000_0101)
+            .r0c(0x0000_0045)
+            .r0d(0x0000_005a)
+            .r0e(0x0000_0012)
+            .r0f(0x0000_0063)
+            .build();
+        let mut mock = MockDevice::with_device_ecc();
+
+        mock.program_eeprom(&raw, &mut NoopDelay::new())
+            .expect("data bits match");
+
+        assert_eq!(mock.writes[1], (Acs37800Register::R0B, 0x0000_0101));
+    }
+
+    #[test]
+    fn program_eeprom_reports_failed_register_and_relocks() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(pack_r0b(0x101, 0x155, 0b011, true, false))
+            .r0c(pack_r0c(0x45, 0x155, 0x3A))
+            .r0d(pack_r0d(true, 0b010, 0x5A, 0b111))
+            .r0e(pack_r0e(0x12, 0x21, 0x11, false, true, false, true, false))
+            .r0f(pack_r0f(0x63, false, 0b01, 0b10, 0x12C, true))
+            .build();
+        let mut mock = MockDevice::with_read_only(Acs37800Register::R0D);
+        mock.set_reg(Acs37800Register::R0D, pack_r0d(false, 0, 0x46, 0));
+
+        let err = mock
+            .program_eeprom(&raw, &mut NoopDelay::new())
+            .expect_err("verification should fail");
+
+        match err {
+            Acs37800EepromWriteError::Verify {
+                register,
+                expected,
+                actual,
+            } => {
+                assert_eq!(register, Acs37800Register::R0D);
+                assert_eq!(expected, pack_r0d(true, 0b010, 0x5A, 0b111));
+                assert_eq!(actual, pack_r0d(false, 0, 0x46, 0));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert_eq!(
+            mock.writes.last(),
+            Some(&(Acs37800Register::R2F, 0)),
+            "device should be relocked"
+        );
+        assert!(
+            !mock
+                .writes
+                .iter()
+                .any(|(reg, _)| *reg == Acs37800Register::R0E),
+            "programming should stop at the failed register"
+        );
+    }
 }
 
 #[cfg(all(test, feature = "async"))]
 mod async_tests {
-    use crate::{Acs37800EepromExt, test::assert_is_bus_error};
+    use crate::{
+        Acs37800AccessAsyncExt,
+        test::{AsyncMockDevice, MockDevice, assert_is_bus_error},
+    };
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, NoopDelay, Transaction as DelayTransaction};
 
     use super::test_support::*;
     use super::*;
 
     #[tokio::test]
     async fn read_eeprom_raw_gathers_all_registers_async() {
-        let mut mock = MockDevice::default();
+        let mut mock = AsyncMockDevice::default();
         mock.set_reg(
-            Acs37800EepromRegister::R0B,
+            Acs37800Register::R0B,
             pack_r0b(0x101, 0x155, 0b011, true, false),
         );
-        mock.set_reg(Acs37800EepromRegister::R0C, pack_r0c(0x45, 0x155, 0x3A));
-        mock.set_reg(
-            Acs37800EepromRegister::R0D,
-            pack_r0d(true, 0b010, 0x5A, 0b111),
-        );
+        mock.set_reg(Acs37800Register::R0C, pack_r0c(0x45, 0x155, 0x3A));
+        mock.set_reg(Acs37800Register::R0D, pack_r0d(true, 0b010, 0x5A, 0b111));
         mock.set_reg(
-            Acs37800EepromRegister::R0E,
+            Acs37800Register::R0E,
             pack_r0e(0x12, 0x21, 0x11, false, true, false, true, false),
         );
         mock.set_reg(
-            Acs37800EepromRegister::R0F,
+            Acs37800Register::R0F,
             pack_r0f(0x63, false, 0b01, 0b10, 0x12C, true),
         );
 
@@ -744,22 +1337,19 @@ mod async_tests {
 
     #[tokio::test]
     async fn read_eeprom_interprets_signed_and_flags_async() {
-        let mut mock = MockDevice::default();
+        let mut mock = AsyncMockDevice::default();
         mock.set_reg(
-            Acs37800EepromRegister::R0B,
+            Acs37800Register::R0B,
             pack_r0b(0x1A5, 0x2D3, 0b010, true, true),
         );
-        mock.set_reg(Acs37800EepromRegister::R0C, pack_r0c(0x40, 0x155, 0xF6));
-        mock.set_reg(
-            Acs37800EepromRegister::R0D,
-            pack_r0d(true, 0b101, 0xAA, 0b110),
-        );
+        mock.set_reg(Acs37800Register::R0C, pack_r0c(0x40, 0x155, 0xF6));
+        mock.set_reg(Acs37800Register::R0D, pack_r0d(true, 0b101, 0xAA, 0b110));
         mock.set_reg(
-            Acs37800EepromRegister::R0E,
+            Acs37800Register::R0E,
             pack_r0e(0x17, 0x2A, 0x18, true, true, false, true, false),
         );
         mock.set_reg(
-            Acs37800EepromRegister::R0F,
+            Acs37800Register::R0F,
             pack_r0f(0x52, true, 0b10, 0b01, 0x1F3, true),
         );
 
@@ -769,6 +1359,7 @@ mod async_tests {
         assert_eq!(eeprom.sns_fine_codes, -301);
         assert_eq!(eeprom.vchan_offset_codes, -10);
         assert_eq!(eeprom.crs_sns, 0b010);
+        assert_eq!(eeprom.coarse_gain, 3.0);
         assert!(eeprom.iavgsel_enabled);
         assert!(eeprom.pavgsel_enabled);
         assert_eq!(eeprom.rms_avg_1, 0x40);
@@ -787,19 +1378,161 @@ mod async_tests {
         assert!(!eeprom.zerocross_rising_edge);
         assert_eq!(eeprom.i2c_address_7bit, 0x52);
         assert!(eeprom.i2c_address_disabled);
-        assert_eq!(eeprom.dio0_sel_raw, 0b10);
-        assert_eq!(eeprom.dio1_sel_raw, 0b01);
+        assert_eq!(eeprom.dio0_function, Acs37800Dio0Function::Undervoltage);
+        assert_eq!(eeprom.dio1_function, Acs37800Dio1Function::Undervoltage);
         assert_eq!(eeprom.n_cycles, 0x1F3);
         assert!(eeprom.bypass_n_en);
     }
 
     #[tokio::test]
     async fn read_eeprom_propagates_errors_async() {
-        let mut mock = MockDevice::with_failure(Acs37800EepromRegister::R0C);
-        mock.set_reg(Acs37800EepromRegister::R0B, pack_r0b(0, 0, 0, false, false));
+        let mut mock = AsyncMockDevice(MockDevice::with_failure(Acs37800Register::R0C));
+        mock.set_reg(Acs37800Register::R0B, pack_r0b(0, 0, 0, false, false));
         let err = mock.read_eeprom_raw().await.unwrap_err();
         assert_is_bus_error(&err);
     }
+
+    #[tokio::test]
+    async fn read_shadow_reads_volatile_copies_async() {
+        let mut mock = AsyncMockDevice::default();
+        mock.set_reg(
+            Acs37800Register::R1B,
+            pack_r0b(0x1A5, 0x2D3, 0b010, true, true),
+        );
+        mock.set_reg(Acs37800Register::R1C, pack_r0c(0x40, 0x155, 0xF6));
+        mock.set_reg(Acs37800Register::R1D, pack_r0d(true, 0b101, 0xAA, 0b110));
+        mock.set_reg(
+            Acs37800Register::R1E,
+            pack_r0e(0x17, 0x2A, 0x18, true, true, false, true, false),
+        );
+        mock.set_reg(
+            Acs37800Register::R1F,
+            pack_r0f(0x52, true, 0b10, 0b01, 0x1F3, true),
+        );
+
+        let shadow = mock.read_shadow().await.expect("parsed shadow");
+        assert_eq!(shadow.qvo_fine_codes, -91);
+        assert_eq!(shadow.vchan_offset_codes, -10);
+        assert_eq!(shadow.fault_threshold_codes, 0xAA);
+        assert_eq!(shadow.overvoltage_threshold_codes, 0x2A);
+        assert_eq!(shadow.n_cycles, 0x1F3);
+    }
+
+    #[tokio::test]
+    async fn write_shadow_raw_targets_shadow_registers_async() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(pack_r0b(0x101, 0x155, 0b011, true, false))
+            .r0c(pack_r0c(0x45, 0x155, 0x3A))
+            .r0d(pack_r0d(true, 0b010, 0x5A, 0b111))
+            .r0e(pack_r0e(0x12, 0x21, 0x11, false, true, false, true, false))
+            .r0f(pack_r0f(0x63, false, 0b01, 0b10, 0x12C, true))
+            .build();
+        let mut mock = AsyncMockDevice::default();
+
+        let mut unlocked = mock.unlock().await.expect("unlocked");
+        unlocked
+            .write_shadow_raw(&raw)
+            .await
+            .expect("shadow written");
+        unlocked.lock().await.expect("locked");
+
+        let written: Vec<_> = mock.writes[1..6].iter().map(|(reg, _)| *reg).collect();
+        assert_eq!(
+            written,
+            [
+                Acs37800Register::R1B,
+                Acs37800Register::R1C,
+                Acs37800Register::R1D,
+                Acs37800Register::R1E,
+                Acs37800Register::R1F,
+            ]
+        );
+        let shadow = mock.read_shadow_raw().await.expect("shadow");
+        assert_eq!(shadow.r0c.rms_avg_1(), 0x45);
+        assert_eq!(shadow.r0f.n(), 0x12C);
+    }
+
+    #[tokio::test]
+    async fn program_eeprom_writes_verifies_and_relocks_async() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(pack_r0b(0x101, 0x155, 0b011, true, false))
+            .r0c(pack_r0c(0x45, 0x155, 0x3A))
+            .r0d(pack_r0d(true, 0b010, 0x5A, 0b111))
+            .r0e(pack_r0e(0x12, 0x21, 0x11, false, true, false, true, false))
+            .r0f(pack_r0f(0x63, false, 0b01, 0b10, 0x12C, true))
+            .build();
+        let mut mock = AsyncMockDevice::default();
+        let mut delay =
+            CheckedDelay::new(&vec![
+                DelayTransaction::async_delay_ms(EEPROM_WRITE_TIME_MS);
+                5
+            ]);
+
+        mock.program_eeprom(&raw, &mut delay)
+            .await
+            .expect("programmed");
+
+        let written: Vec<_> = mock.writes.iter().map(|(reg, _)| *reg).collect();
+        assert_eq!(
+            written,
+            [
+                Acs37800Register::R2F,
+                Acs37800Register::R0B,
+                Acs37800Register::R0C,
+                Acs37800Register::R0D,
+                Acs37800Register::R0E,
+                Acs37800Register::R0F,
+                Acs37800Register::R2F,
+            ]
+        );
+        assert!(!mock.is_unlocked().await.expect("status"));
+        let eeprom = mock.read_eeprom_raw().await.expect("eeprom");
+        assert_eq!(eeprom.r0d.fault(), 0x5A);
+        delay.done();
+    }
+
+    #[tokio::test]
+    async fn program_eeprom_reports_failed_register_and_relocks_async() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(pack_r0b(0x101, 0x155, 0b011, true, false))
+            .r0c(pack_r0c(0x45, 0x155, 0x3A))
+            .r0d(pack_r0d(true, 0b010, 0x5A, 0b111))
+            .r0e(pack_r0e(0x12, 0x21, 0x11, false, true, false, true, false))
+            .r0f(pack_r0f(0x63, false, 0b01, 0b10, 0x12C, true))
+            .build();
+        let mut mock = AsyncMockDevice(MockDevice::with_read_only(Acs37800Register::R0D));
+        mock.set_reg(Acs37800Register::R0D, pack_r0d(false, 0, 0x46, 0));
+
+        let err = mock
+            .program_eeprom(&raw, &mut NoopDelay::new())
+            .await
+            .expect_err("verification should fail");
+
+        match err {
+            Acs37800EepromWriteError::Verify {
+                register,
+                expected,
+                actual,
+            } => {
+                assert_eq!(register, Acs37800Register::R0D);
+                assert_eq!(expected, pack_r0d(true, 0b010, 0x5A, 0b111));
+                assert_eq!(actual, pack_r0d(false, 0, 0x46, 0));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert_eq!(
+            mock.writes.last(),
+            Some(&(Acs37800Register::R2F, 0)),
+            "device should be relocked"
+        );
+        assert!(
+            !mock
+                .writes
+                .iter()
+                .any(|(reg, _)| *reg == Acs37800Register::R0E),
+            "programming should stop at the failed register"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -815,4 +1548,138 @@ mod sign_extend_tests {
     fn sign_extend_handles_negative_values() {
         assert_eq!(sign_extend(0b1110, 4), -2);
     }
+
+    #[test]
+    fn sign_extend_ignores_bits_above_width() {
+        assert_eq!(sign_extend(0xfff3, 4), 3);
+    }
+
+    #[test]
+    fn sign_extend_handles_full_width() {
+        assert_eq!(sign_extend(0x8000, 16), i16::MIN);
+        assert_eq!(sign_extend(0x7fff, 16), i16::MAX);
+    }
+}
+
+#[cfg(test)]
+mod conversion_tests {
+    use crate::EEPROM_DATA_MASK;
+
+    use super::test_support::*;
+    use super::*;
+
+    #[test]
+    fn vevent_cycles_convert_to_milliseconds() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(0)
+            .r0c(0)
+            .r0d(0)
+            .r0e(Eeprom0eRaw::new().with_vevent_cycs(5))
+            .r0f(0)
+            .build();
+        let eeprom = Acs37800Eeprom::from(raw);
+
+        assert_eq!(eeprom.vevent_duration_ms(50.0), 100.0);
+        assert_eq!(Acs37800Eeprom::vevent_cycles_from_ms(100.0, 50.0), Ok(5));
+        assert_eq!(Acs37800Eeprom::vevent_cycles_from_ms(100.0, 60.0), Ok(6));
+        assert!(Acs37800Eeprom::vevent_cycles_from_ms(2000.0, 50.0).is_err());
+    }
+
+    #[test]
+    fn interpreted_view_round_trips_to_raw() {
+        // Every reserved bit set
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(pack_r0b(0x1c3, 0x2d3, 0b010, true, false) | 0x0300_0000)
+            .r0c(pack_r0c(0x40, 0x3ff, 0xf6) | 0x0200_0000)
+            .r0d(pack_r0d(true, 0b101, 0x5a, 0b011) | 0x0300_117f)
+            .r0e(pack_r0e(0x12, 0x21, 0x11, true, true, false, true, false) | 0x0200_00c0)
+            .r0f(pack_r0f(0x63, true, 0b01, 0b10, 0x12c, true) | 0x0200_0003)
+            .build()
+            .with_ecc();
+        let eeprom = Acs37800Eeprom::from(raw);
+        assert_eq!(eeprom.qvo_fine_codes, -61);
+        assert_eq!(eeprom.vchan_offset_codes, -10);
+
+        let mut packed = raw;
+        eeprom.apply_to(&mut packed).expect("in range");
+        assert_eq!(packed.eeprom_words(), raw.eeprom_words());
+
+        // Editing one field leaves the reserved bits of its register alone
+        let edited = Acs37800Eeprom {
+            fault_threshold_codes: 0x21,
+            ..eeprom
+        };
+        let mut packed = raw;
+        edited.apply_to(&mut packed).expect("in range");
+        assert_eq!(packed.r0d.fault(), 0x21);
+        assert_eq!(packed.r0d.into_bits() & 0x0300_117f, 0x0300_117f);
+        assert_eq!(Acs37800Eeprom::from(packed).chan_delay_sel, 0b101);
+    }
+
+    #[test]
+    fn try_from_packs_fresh_words() {
+        let raw = Acs37800EepromRaw::builder()
+            .r0b(pack_r0b(0x1c3, 0x2d3, 0b010, true, false))
+            .r0c(pack_r0c(0x40, 0x3ff, 0xf6))
+            .r0d(pack_r0d(true, 0b101, 0x5a, 0b011))
+            .r0e(pack_r0e(0x12, 0x21, 0x11, true, true, false, true, false))
+            .r0f(pack_r0f(0x63, true, 0b01, 0b10, 0x12c, true))
+            .build();
+
+        let packed = Acs37800EepromRaw::try_from(Acs37800Eeprom::from(raw)).expect("in range");
+        for ((register, expected), (_, actual)) in
+            raw.eeprom_words().into_iter().zip(packed.eeprom_words())
+        {
+            assert_eq!(actual, expected & EEPROM_DATA_MASK, "{register:?}");
+        }
+    }
+
+    #[test]
+    fn out_of_range_fields_do_not_pack() {
+        let eeprom = Acs37800Eeprom::from(
+            Acs37800EepromRaw::builder()
+                .r0b(0)
+                .r0c(0)
+                .r0d(0)
+                .r0e(0)
+                .r0f(0)
+                .build(),
+        );
+
+        let err = Acs37800EepromRaw::try_from(Acs37800Eeprom {
+            sns_fine_codes: -513,
+            ..eeprom
+        })
+        .expect_err("below range");
+        assert!(matches!(
+            err,
+            Acs37800EepromEncodeError::Range(range)
+                if range.field == "sns_fine_codes" && range.min == -512.0
+        ));
+
+        assert!(
+            Acs37800EepromRaw::try_from(Acs37800Eeprom {
+                qvo_fine_codes: 256,
+                ..eeprom
+            })
+            .is_err()
+        );
+        assert!(
+            Acs37800EepromRaw::try_from(Acs37800Eeprom {
+                n_cycles: 1024,
+                ..eeprom
+            })
+            .is_err()
+        );
+        let err = Acs37800EepromRaw::try_from(Acs37800Eeprom {
+            zerocross_pulse_width_us: 64,
+            ..eeprom
+        })
+        .expect_err("no such pulse width");
+        assert_eq!(err, Acs37800EepromEncodeError::ZerocrossPulseWidth(64));
+        assert_eq!(
+            err.to_string(),
+            "zerocross_pulse_width_us of 64 is neither 32 nor 256"
+        );
+    }
 }
@@ -53,13 +53,15 @@
 
 use bon::Builder;
 
-#[cfg(feature = "async")]
-use embedded_hal_async::i2c::I2c;
+use embedded_hal::i2c::{Error, I2c};
 
-#[cfg(not(feature = "async"))]
-use embedded_hal::i2c::I2c;
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::I2c as AsyncI2c;
 
-use super::{Acs37800, Acs37800EepromRegister, Acs37800ReadError};
+use super::{
+    Acs37800, Acs37800BusError, Acs37800BusErrorKind, Acs37800BusOperation, Acs37800ReadError,
+    Acs37800Register, Acs37800Scaling, Acs37800WriteError,
+};
 
 /// ## Default I²C base address for DIO pin voltage addressing.
 ///
@@ -87,67 +89,102 @@ pub const I2C_ADDRESS_MEASURED_BASE: u8 = 0x60;
 /// - [ACS37800 Datasheet, rev 4, page 31](https://www.allegromicro.com/-/media/files/datasheets/acs37800-datasheet.pdf)
 pub const I2C_ADDRESS_PROGRAMMED_DEFAULT: u8 = 0x7F;
 
+/// Driver for the I²C variants, implements [`Acs37800`] over a blocking bus and
+/// `Acs37800Async` over an async one when the `async` feature is enabled.
 #[derive(Builder)]
-pub struct Acs37800I2c<I2C: I2c> {
+pub struct Acs37800I2c<I2C> {
     i2c: I2C,
     #[builder(default = 0x60)]
     address: u8,
+    /// Board scaling for the readers in [`Acs37800ScaledExt`](crate::Acs37800ScaledExt).
+    scaling: Option<Acs37800Scaling>,
 }
 
 impl<I2C: I2c> Acs37800 for Acs37800I2c<I2C> {
-    #[cfg(feature = "async")]
-    fn read_reg32(
-        &mut self,
-        reg: Acs37800EepromRegister,
-    ) -> impl Future<Output = Result<u32, Acs37800ReadError>> {
-        async move {
-            let mut buf = [0u8; 4];
+    fn read_reg32(&mut self, reg: Acs37800Register) -> Result<u32, Acs37800ReadError> {
+        let mut buf = [0u8; 4];
 
-            let result = self
-                .i2c
-                .write_read(self.address, &[reg as u8], &mut buf)
-                .await;
+        let result = self.i2c.write_read(self.address, &[reg as u8], &mut buf);
+        result.map_err(|cause| bus_error(reg, Acs37800BusOperation::Read, cause))?;
+
+        Ok(u32::from_le_bytes(buf))
+    }
 
-            #[cfg(feature = "std")]
-            {
-                result.map_err(|cause| Acs37800ReadError::Io(format!("{cause:?}")))?;
-            }
+    fn write_reg32(&mut self, reg: Acs37800Register, value: u32) -> Result<(), Acs37800WriteError> {
+        let buf = write_frame(reg, value);
 
-            #[cfg(not(feature = "std"))]
-            {
-                result.map_err(|_| Acs37800ReadError::Io)?;
-            }
+        let result = self.i2c.write(self.address, &buf);
+        result.map_err(|cause| bus_error(reg, Acs37800BusOperation::Write, cause))?;
 
-            Ok(u32::from_le_bytes(buf))
-        }
+        Ok(())
     }
 
-    #[cfg(not(feature = "async"))]
-    fn read_reg32(&mut self, reg: Acs37800EepromRegister) -> Result<u32, Acs37800ReadError> {
+    fn scaling(&self) -> Option<Acs37800Scaling> {
+        self.scaling
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C: AsyncI2c> crate::Acs37800Async for Acs37800I2c<I2C> {
+    async fn read_reg32(&mut self, reg: Acs37800Register) -> Result<u32, Acs37800ReadError> {
         let mut buf = [0u8; 4];
 
-        let result = self.i2c.write_read(self.address, &[reg as u8], &mut buf);
+        let result = self
+            .i2c
+            .write_read(self.address, &[reg as u8], &mut buf)
+            .await;
+        result.map_err(|cause| bus_error(reg, Acs37800BusOperation::Read, cause))?;
 
-        #[cfg(feature = "std")]
-        {
-            result.map_err(|cause| Acs37800ReadError::Io(format!("{cause:?}")))?;
-        }
+        Ok(u32::from_le_bytes(buf))
+    }
 
-        #[cfg(not(feature = "std"))]
-        {
-            result.map_err(|_| Acs37800ReadError::Io)?;
-        }
+    async fn write_reg32(
+        &mut self,
+        reg: Acs37800Register,
+        value: u32,
+    ) -> Result<(), Acs37800WriteError> {
+        let buf = write_frame(reg, value);
 
-        Ok(u32::from_le_bytes(buf))
+        let result = self.i2c.write(self.address, &buf).await;
+        result.map_err(|cause| bus_error(reg, Acs37800BusOperation::Write, cause))?;
+
+        Ok(())
+    }
+
+    fn scaling(&self) -> Option<Acs37800Scaling> {
+        self.scaling
+    }
+}
+
+/// Register address followed by the little-endian register value, as expected by the device.
+fn write_frame(reg: Acs37800Register, value: u32) -> [u8; 5] {
+    let [b0, b1, b2, b3] = value.to_le_bytes();
+    [reg as u8, b0, b1, b2, b3]
+}
+
+/// Failed transfer to or from `reg`, keeping the kind of bus error.
+fn bus_error(
+    reg: Acs37800Register,
+    operation: Acs37800BusOperation,
+    cause: impl Error,
+) -> Acs37800BusError {
+    Acs37800BusError {
+        register: reg,
+        operation,
+        kind: Acs37800BusErrorKind::I2c(cause.kind()),
     }
 }
 
-#[cfg(all(test, not(feature = "async")))]
+#[cfg(test)]
 mod tests {
-    use embedded_hal::i2c::ErrorKind;
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
     use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
 
-    use crate::test::assert_is_bus_error;
+    use crate::test::{assert_is_bus_error, assert_is_write_bus_error};
+    use crate::{
+        Acs37800CurrentPhase, Acs37800MeasurementExt, Acs37800PowerDirection, Acs37800ScaledExt,
+        Acs37800Variant,
+    };
 
     use super::*;
 
@@ -160,13 +197,13 @@ mod tests {
     fn read_reg32_returns_le_word() {
         let expectations = [I2cTransaction::write_read(
             0x60,
-            vec![Acs37800EepromRegister::R0C as u8],
+            vec![Acs37800Register::R0C as u8],
             vec![0x78, 0x56, 0x34, 0x12],
         )];
         let mut driver = new_driver(&expectations);
 
         let value = driver
-            .read_reg32(Acs37800EepromRegister::R0C)
+            .read_reg32(Acs37800Register::R0C)
             .expect("read value");
         assert_eq!(value, 0x1234_5678);
 
@@ -177,32 +214,291 @@ mod tests {
     fn read_reg32_maps_bus_errors() {
         let expectations =
             [
-                I2cTransaction::write_read(
-                    0x60,
-                    vec![Acs37800EepromRegister::R0B as u8],
-                    vec![0; 4],
-                )
-                .with_error(ErrorKind::Other),
+                I2cTransaction::write_read(0x60, vec![Acs37800Register::R0B as u8], vec![0; 4])
+                    .with_error(ErrorKind::Other),
             ];
         let mut driver = new_driver(&expectations);
 
         let err = driver
-            .read_reg32(Acs37800EepromRegister::R0B)
+            .read_reg32(Acs37800Register::R0B)
             .expect_err("should propagate error");
         assert_is_bus_error(&err);
 
         driver.i2c.done();
     }
+
+    #[test]
+    fn read_reg32_reports_missing_device() {
+        let expectations = [
+            I2cTransaction::write_read(0x60, vec![Acs37800Register::R20 as u8], vec![0; 4])
+                .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+            I2cTransaction::write_read(0x60, vec![Acs37800Register::R20 as u8], vec![0; 4])
+                .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data)),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let Err(Acs37800ReadError::Io(absent)) = driver.read_reg32(Acs37800Register::R20) else {
+            panic!("should propagate error");
+        };
+        assert!(absent.is_device_absent());
+        assert_eq!(absent.register, Acs37800Register::R20);
+        assert_eq!(absent.operation, Acs37800BusOperation::Read);
+
+        let Err(Acs37800ReadError::Io(glitch)) = driver.read_reg32(Acs37800Register::R20) else {
+            panic!("should propagate error");
+        };
+        assert!(!glitch.is_device_absent());
+        assert_eq!(
+            glitch.kind,
+            Acs37800BusErrorKind::I2c(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data))
+        );
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn write_reg32_sends_le_word() {
+        let expectations = [I2cTransaction::write(
+            0x60,
+            vec![Acs37800Register::R0E as u8, 0x78, 0x56, 0x34, 0x12],
+        )];
+        let mut driver = new_driver(&expectations);
+
+        driver
+            .write_reg32(Acs37800Register::R0E, 0x1234_5678)
+            .expect("write value");
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn write_reg32_maps_bus_errors() {
+        let expectations =
+            [
+                I2cTransaction::write(0x60, vec![Acs37800Register::R0F as u8, 0, 0, 0, 0])
+                    .with_error(ErrorKind::Other),
+            ];
+        let mut driver = new_driver(&expectations);
+
+        let err = driver
+            .write_reg32(Acs37800Register::R0F, 0)
+            .expect_err("should propagate error");
+        assert_is_write_bus_error(&err);
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_rms_decodes_register_0x20() {
+        let expectations = [I2cTransaction::write_read(
+            0x60,
+            vec![Acs37800Register::R20 as u8],
+            vec![0x00, 0xc0, 0x00, 0x40],
+        )];
+        let mut driver = new_driver(&expectations);
+
+        let rms = driver.read_rms().expect("rms");
+        assert_eq!(rms.vrms_codes, 0xc000);
+        assert_eq!(rms.vrms_normalized, 0.75);
+        assert_eq!(rms.irms_codes, 0x4000);
+        assert_eq!(rms.irms_normalized, 0.25);
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_power_decodes_register_0x21() {
+        let expectations = [I2cTransaction::write_read(
+            0x60,
+            vec![Acs37800Register::R21 as u8],
+            vec![0x00, 0xc0, 0x00, 0x20],
+        )];
+        let mut driver = new_driver(&expectations);
+
+        let power = driver.read_power().expect("power");
+        assert_eq!(power.pactive_codes, -0x4000);
+        assert_eq!(power.pimag_codes, 0x2000);
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_apparent_power_decodes_register_0x22() {
+        let expectations = [I2cTransaction::write_read(
+            0x60,
+            vec![Acs37800Register::R22 as u8],
+            vec![0x00, 0x80, 0x00, 0x0e],
+        )];
+        let mut driver = new_driver(&expectations);
+
+        let apparent = driver.read_apparent_power().expect("apparent power");
+        assert_eq!(apparent.papparent_codes, 0x8000);
+        assert_eq!(apparent.pfactor_codes, -512);
+        assert_eq!(apparent.current_phase, Acs37800CurrentPhase::Lagging);
+        assert_eq!(apparent.direction, Acs37800PowerDirection::Export);
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_averages_reads_registers_0x26_to_0x29() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R26 as u8],
+                vec![0x00, 0x80, 0x00, 0x40],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R27 as u8],
+                vec![0x00, 0x40, 0x00, 0x20],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R28 as u8],
+                vec![0x00, 0xe0, 0x00, 0x00],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R29 as u8],
+                vec![0x00, 0x20, 0x00, 0x00],
+            ),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let averages = driver.read_averages().expect("averages");
+        assert_eq!(averages.rms_one_sec.vrms_codes, 0x8000);
+        assert_eq!(averages.rms_one_sec.irms_codes, 0x4000);
+        assert_eq!(averages.rms_one_min.vrms_codes, 0x4000);
+        assert_eq!(averages.rms_one_min.irms_codes, 0x2000);
+        assert_eq!(averages.active_power_one_sec.pactive_codes, -0x2000);
+        assert_eq!(averages.active_power_one_min.pactive_codes, 0x2000);
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_instantaneous_decodes_registers_0x2a_and_0x2c() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R2A as u8],
+                vec![0x00, 0x40, 0x00, 0xc0],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R2C as u8],
+                vec![0x00, 0x00, 0x00, 0xf0],
+            ),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let sample = driver.read_instantaneous().expect("sample");
+        assert_eq!(sample.vcodes, 0x4000);
+        assert_eq!(sample.icodes, -0x4000);
+        let power = driver.read_instantaneous_power().expect("power");
+        assert_eq!(power.pinstant_codes, -0x1000_0000);
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_averaging_window_combines_sample_count_and_config() {
+        // n = 400, bypass_n_en = 1
+        let r1f = (400u32 << 14) | (1 << 24);
+        let expectations = [
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R25 as u8],
+                vec![0x90, 0x01, 0x00, 0x00],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R1F as u8],
+                r1f.to_le_bytes().to_vec(),
+            ),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let window = driver.read_averaging_window().expect("window");
+        assert_eq!(window.samples, 400);
+        assert_eq!(window.configured_samples, Some(400));
+        assert!(!window.is_truncated());
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_rms_scaled_uses_driver_scaling() {
+        // 27500 codes each, half of the full-scale input
+        let expectations = [I2cTransaction::write_read(
+            0x60,
+            vec![Acs37800Register::R20 as u8],
+            vec![0x6c, 0x6b, 0x6c, 0x6b],
+        )];
+        let i2c = I2cMock::new(&expectations);
+        let scaling = Acs37800Scaling::builder()
+            .divider_resistance_ohms(999_000.0)
+            .sense_resistance_ohms(1_000.0)
+            .variant(Acs37800Variant::Range30A)
+            .build()
+            .expect("valid scaling");
+        let mut driver = Acs37800I2c::builder().i2c(i2c).scaling(scaling).build();
+
+        let rms = driver.read_rms_scaled().expect("rms");
+        assert!((rms.volts - 125.0).abs() < 1e-3);
+        assert!((rms.amps - 15.0).abs() < 1e-4);
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_rms_scaled_requires_scaling() {
+        let mut driver = new_driver(&[]);
+
+        let err = driver.read_rms_scaled().expect_err("no scaling");
+        assert!(matches!(err, Acs37800ReadError::NoScaling));
+
+        driver.i2c.done();
+    }
+
+    #[test]
+    fn read_status_and_clear_fault_latch() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R2D as u8],
+                vec![0b0000_0110, 0x00, 0x00, 0x00],
+            ),
+            I2cTransaction::write(
+                0x60,
+                vec![Acs37800Register::R2D as u8, 0b0000_0100, 0x00, 0x00, 0x00],
+            ),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let status = driver.read_status().expect("status");
+        assert!(status.fault);
+        assert!(status.fault_latched);
+        assert!(!status.overvoltage);
+        driver.clear_fault_latch().expect("cleared");
+
+        driver.i2c.done();
+    }
 }
 
 #[cfg(all(test, feature = "async"))]
 mod async_tests {
-    use embedded_hal::i2c::ErrorKind;
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
     use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
 
-    use crate::test::assert_is_bus_error;
-
-    use super::*;
+    use crate::i2c::Acs37800I2c;
+    use crate::test::{assert_is_bus_error, assert_is_write_bus_error};
+    use crate::{
+        Acs37800Async, Acs37800BusOperation, Acs37800CurrentPhase, Acs37800MeasurementAsyncExt,
+        Acs37800PowerDirection, Acs37800ReadError, Acs37800Register, Acs37800ScaledAsyncExt,
+        Acs37800Scaling, Acs37800Variant, Acs37800WriteError,
+    };
 
     fn new_driver(expectations: &[I2cTransaction]) -> Acs37800I2c<I2cMock> {
         let i2c = I2cMock::new(expectations);
@@ -213,13 +509,13 @@ mod async_tests {
     async fn read_reg32_returns_le_word_async() {
         let expectations = [I2cTransaction::write_read(
             0x60,
-            vec![Acs37800EepromRegister::R0C as u8],
+            vec![Acs37800Register::R0C as u8],
             vec![0x78, 0x56, 0x34, 0x12],
         )];
         let mut driver = new_driver(&expectations);
 
         let value = driver
-            .read_reg32(Acs37800EepromRegister::R0C)
+            .read_reg32(Acs37800Register::R0C)
             .await
             .expect("read value");
         assert_eq!(value, 0x1234_5678);
@@ -231,21 +527,270 @@ mod async_tests {
     async fn read_reg32_maps_bus_errors_async() {
         let expectations =
             [
-                I2cTransaction::write_read(
-                    0x60,
-                    vec![Acs37800EepromRegister::R0B as u8],
-                    vec![0; 4],
-                )
-                .with_error(ErrorKind::Other),
+                I2cTransaction::write_read(0x60, vec![Acs37800Register::R0B as u8], vec![0; 4])
+                    .with_error(ErrorKind::Other),
             ];
         let mut driver = new_driver(&expectations);
 
         let err = driver
-            .read_reg32(Acs37800EepromRegister::R0B)
+            .read_reg32(Acs37800Register::R0B)
             .await
             .expect_err("should propagate error");
         assert_is_bus_error(&err);
 
         driver.i2c.done();
     }
+
+    #[tokio::test]
+    async fn write_reg32_reports_missing_device_async() {
+        let expectations =
+            [
+                I2cTransaction::write(0x60, vec![Acs37800Register::R2F as u8, 0, 0, 0, 0])
+                    .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+            ];
+        let mut driver = new_driver(&expectations);
+
+        let Err(Acs37800WriteError::Io(absent)) =
+            driver.write_reg32(Acs37800Register::R2F, 0).await
+        else {
+            panic!("should propagate error");
+        };
+        assert!(absent.is_device_absent());
+        assert_eq!(absent.register, Acs37800Register::R2F);
+        assert_eq!(absent.operation, Acs37800BusOperation::Write);
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn write_reg32_sends_le_word_async() {
+        let expectations = [I2cTransaction::write(
+            0x60,
+            vec![Acs37800Register::R0E as u8, 0x78, 0x56, 0x34, 0x12],
+        )];
+        let mut driver = new_driver(&expectations);
+
+        driver
+            .write_reg32(Acs37800Register::R0E, 0x1234_5678)
+            .await
+            .expect("write value");
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn write_reg32_maps_bus_errors_async() {
+        let expectations =
+            [
+                I2cTransaction::write(0x60, vec![Acs37800Register::R0F as u8, 0, 0, 0, 0])
+                    .with_error(ErrorKind::Other),
+            ];
+        let mut driver = new_driver(&expectations);
+
+        let err = driver
+            .write_reg32(Acs37800Register::R0F, 0)
+            .await
+            .expect_err("should propagate error");
+        assert_is_write_bus_error(&err);
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_rms_decodes_register_0x20_async() {
+        let expectations = [I2cTransaction::write_read(
+            0x60,
+            vec![Acs37800Register::R20 as u8],
+            vec![0x00, 0xc0, 0x00, 0x40],
+        )];
+        let mut driver = new_driver(&expectations);
+
+        let rms = driver.read_rms().await.expect("rms");
+        assert_eq!(rms.vrms_codes, 0xc000);
+        assert_eq!(rms.vrms_normalized, 0.75);
+        assert_eq!(rms.irms_codes, 0x4000);
+        assert_eq!(rms.irms_normalized, 0.25);
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_power_decodes_register_0x21_async() {
+        let expectations = [I2cTransaction::write_read(
+            0x60,
+            vec![Acs37800Register::R21 as u8],
+            vec![0x00, 0xc0, 0x00, 0x20],
+        )];
+        let mut driver = new_driver(&expectations);
+
+        let power = driver.read_power().await.expect("power");
+        assert_eq!(power.pactive_codes, -0x4000);
+        assert_eq!(power.pimag_codes, 0x2000);
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_apparent_power_decodes_register_0x22_async() {
+        let expectations = [I2cTransaction::write_read(
+            0x60,
+            vec![Acs37800Register::R22 as u8],
+            vec![0x00, 0x80, 0x00, 0x0e],
+        )];
+        let mut driver = new_driver(&expectations);
+
+        let apparent = driver.read_apparent_power().await.expect("apparent power");
+        assert_eq!(apparent.papparent_codes, 0x8000);
+        assert_eq!(apparent.pfactor_codes, -512);
+        assert_eq!(apparent.current_phase, Acs37800CurrentPhase::Lagging);
+        assert_eq!(apparent.direction, Acs37800PowerDirection::Export);
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_averages_reads_registers_0x26_to_0x29_async() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R26 as u8],
+                vec![0x00, 0x80, 0x00, 0x40],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R27 as u8],
+                vec![0x00, 0x40, 0x00, 0x20],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R28 as u8],
+                vec![0x00, 0xe0, 0x00, 0x00],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R29 as u8],
+                vec![0x00, 0x20, 0x00, 0x00],
+            ),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let averages = driver.read_averages().await.expect("averages");
+        assert_eq!(averages.rms_one_sec.vrms_codes, 0x8000);
+        assert_eq!(averages.rms_one_sec.irms_codes, 0x4000);
+        assert_eq!(averages.rms_one_min.vrms_codes, 0x4000);
+        assert_eq!(averages.rms_one_min.irms_codes, 0x2000);
+        assert_eq!(averages.active_power_one_sec.pactive_codes, -0x2000);
+        assert_eq!(averages.active_power_one_min.pactive_codes, 0x2000);
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_instantaneous_decodes_registers_0x2a_and_0x2c_async() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R2A as u8],
+                vec![0x00, 0x40, 0x00, 0xc0],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R2C as u8],
+                vec![0x00, 0x00, 0x00, 0xf0],
+            ),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let sample = driver.read_instantaneous().await.expect("sample");
+        assert_eq!(sample.vcodes, 0x4000);
+        assert_eq!(sample.icodes, -0x4000);
+        let power = driver.read_instantaneous_power().await.expect("power");
+        assert_eq!(power.pinstant_codes, -0x1000_0000);
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_averaging_window_combines_sample_count_and_config_async() {
+        // n = 400, bypass_n_en = 1
+        let r1f = (400u32 << 14) | (1 << 24);
+        let expectations = [
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R25 as u8],
+                vec![0x90, 0x01, 0x00, 0x00],
+            ),
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R1F as u8],
+                r1f.to_le_bytes().to_vec(),
+            ),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let window = driver.read_averaging_window().await.expect("window");
+        assert_eq!(window.samples, 400);
+        assert_eq!(window.configured_samples, Some(400));
+        assert!(!window.is_truncated());
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_rms_scaled_uses_driver_scaling_async() {
+        // 27500 codes each, half of the full-scale input
+        let expectations = [I2cTransaction::write_read(
+            0x60,
+            vec![Acs37800Register::R20 as u8],
+            vec![0x6c, 0x6b, 0x6c, 0x6b],
+        )];
+        let i2c = I2cMock::new(&expectations);
+        let scaling = Acs37800Scaling::builder()
+            .divider_resistance_ohms(999_000.0)
+            .sense_resistance_ohms(1_000.0)
+            .variant(Acs37800Variant::Range30A)
+            .build()
+            .expect("valid scaling");
+        let mut driver = Acs37800I2c::builder().i2c(i2c).scaling(scaling).build();
+
+        let rms = driver.read_rms_scaled().await.expect("rms");
+        assert!((rms.volts - 125.0).abs() < 1e-3);
+        assert!((rms.amps - 15.0).abs() < 1e-4);
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_rms_scaled_requires_scaling_async() {
+        let mut driver = new_driver(&[]);
+
+        let err = driver.read_rms_scaled().await.expect_err("no scaling");
+        assert!(matches!(err, Acs37800ReadError::NoScaling));
+
+        driver.i2c.done();
+    }
+
+    #[tokio::test]
+    async fn read_status_and_clear_fault_latch_async() {
+        let expectations = [
+            I2cTransaction::write_read(
+                0x60,
+                vec![Acs37800Register::R2D as u8],
+                vec![0b0000_0110, 0x00, 0x00, 0x00],
+            ),
+            I2cTransaction::write(
+                0x60,
+                vec![Acs37800Register::R2D as u8, 0b0000_0100, 0x00, 0x00, 0x00],
+            ),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        let status = driver.read_status().await.expect("status");
+        assert!(status.fault);
+        assert!(status.fault_latched);
+        assert!(!status.overvoltage);
+        driver.clear_fault_latch().await.expect("cleared");
+
+        driver.i2c.done();
+    }
 }
@@ -14,33 +14,185 @@ pub mod spi;
 #[cfg(test)]
 pub(crate) mod test;
 
+mod access;
+pub use access::*;
+
+mod config;
+pub use config::*;
+
+mod ecc;
+pub use ecc::*;
+
 mod eeprom;
 pub use eeprom::*;
 
+mod measurement;
+pub use measurement::*;
+
+mod register;
+pub use register::*;
+
+mod scaling;
+pub use scaling::*;
+
+mod settings;
+pub use settings::*;
+
+/// Blocking register access to an ACS37800.
 pub trait Acs37800 {
-    #[cfg(feature = "async")]
+    fn read_reg32(&mut self, reg: Acs37800Register) -> Result<u32, Acs37800ReadError>;
+
+    fn write_reg32(&mut self, reg: Acs37800Register, value: u32) -> Result<(), Acs37800WriteError>;
+
+    /// Board scaling used to convert register codes to physical units, if configured.
+    fn scaling(&self) -> Option<Acs37800Scaling> {
+        None
+    }
+}
+
+/// Async register access to an ACS37800.
+///
+/// Drivers implement it alongside [`Acs37800`], the helpers in the `*AsyncExt` traits mirror the
+/// blocking ones and decode register content the same way.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait Acs37800Async {
     fn read_reg32(
         &mut self,
-        reg: Acs37800EepromRegister,
+        reg: Acs37800Register,
     ) -> impl Future<Output = Result<u32, Acs37800ReadError>>;
 
-    #[cfg(not(feature = "async"))]
-    fn read_reg32(&mut self, reg: Acs37800EepromRegister) -> Result<u32, Acs37800ReadError>;
+    fn write_reg32(
+        &mut self,
+        reg: Acs37800Register,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Acs37800WriteError>>;
+
+    /// Board scaling used to convert register codes to physical units, if configured.
+    fn scaling(&self) -> Option<Acs37800Scaling> {
+        None
+    }
+}
+
+/// Bus transfer that failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800BusOperation {
+    Read,
+    Write,
+}
+
+/// Failure reported by the bus, as classified by `embedded-hal`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Acs37800BusErrorKind {
+    I2c(embedded_hal::i2c::ErrorKind),
+    Spi(embedded_hal::spi::ErrorKind),
+}
+
+impl core::fmt::Display for Acs37800BusErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Acs37800BusErrorKind::I2c(kind) => kind.fmt(f),
+            Acs37800BusErrorKind::Spi(kind) => kind.fmt(f),
+        }
+    }
 }
 
-#[derive(Debug, Error)]
+/// Bus transfer to or from a register that failed.
+#[derive(Debug, Error, Clone, Copy, Eq, PartialEq)]
+#[error("{operation:?} of register {register:?} failed: {kind}")]
+pub struct Acs37800BusError {
+    pub register: Acs37800Register,
+    pub operation: Acs37800BusOperation,
+    pub kind: Acs37800BusErrorKind,
+}
+
+impl Acs37800BusError {
+    /// Whether nothing acknowledged the I²C address, the device is absent or unpowered rather
+    /// than the transfer being disturbed.
+    ///
+    /// Only `NoAcknowledge(Address)` counts. HALs that cannot tell which byte went unacknowledged,
+    /// `linux-embedded-hal` among them, report `NoAcknowledge(Unknown)` and get `false` here even
+    /// for a missing device; match on [`kind`](Self::kind) to treat those as absent too. SPI
+    /// errors are never reported as an absent device.
+    pub fn is_device_absent(&self) -> bool {
+        matches!(
+            self.kind,
+            Acs37800BusErrorKind::I2c(embedded_hal::i2c::ErrorKind::NoAcknowledge(
+                embedded_hal::i2c::NoAcknowledgeSource::Address
+            ))
+        )
+    }
+}
+
+#[derive(Debug, Error, Clone, Copy, Eq, PartialEq)]
 pub enum Acs37800ReadError {
-    #[cfg(feature = "std")]
     #[error("Bus communication error: {0}")]
-    Io(String),
-    #[cfg(not(feature = "std"))]
-    #[error("Bus communication error")]
-    Io,
+    Io(#[from] Acs37800BusError),
+    #[error("Board scaling is not configured on the driver")]
+    NoScaling,
+}
+
+#[derive(Debug, Error, Clone, Copy, Eq, PartialEq)]
+pub enum Acs37800WriteError {
+    #[error("Bus communication error: {0}")]
+    Io(#[from] Acs37800BusError),
+}
+
+/// Value in engineering units that does not fit the register field it is converted into.
+#[derive(Debug, Error, Clone, Copy, PartialEq)]
+#[error("{field} of {value} is outside {min}..={max}")]
+pub struct Acs37800RangeError {
+    pub field: &'static str,
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
 }
 
+impl Acs37800RangeError {
+    /// Returns `value` if it lies within `min..=max`, NaN is always rejected.
+    pub(crate) fn check(field: &'static str, value: f32, min: f32, max: f32) -> Result<f32, Self> {
+        if (min..=max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(Acs37800RangeError {
+                field,
+                value,
+                min,
+                max,
+            })
+        }
+    }
+}
+
+/// Blocking helpers and drivers.
 pub mod prelude {
+    pub use crate::Acs37800AccessExt as _;
     pub use crate::Acs37800EepromExt as _;
+    pub use crate::Acs37800MeasurementExt as _;
+    pub use crate::Acs37800ScaledExt as _;
 
     #[cfg(feature = "i2c")]
     pub use crate::i2c::Acs37800I2c;
+
+    #[cfg(feature = "spi")]
+    pub use crate::spi::Acs37800Spi;
+}
+
+/// Async helpers and drivers.
+///
+/// Kept apart from [`prelude`] since a driver over a bus implementing both the blocking and the
+/// async traits has both sets of helpers, importing both would make every call ambiguous.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub mod prelude_async {
+    pub use crate::Acs37800AccessAsyncExt as _;
+    pub use crate::Acs37800EepromAsyncExt as _;
+    pub use crate::Acs37800MeasurementAsyncExt as _;
+    pub use crate::Acs37800ScaledAsyncExt as _;
+
+    #[cfg(feature = "i2c")]
+    pub use crate::i2c::Acs37800I2c;
+
+    #[cfg(feature = "spi")]
+    pub use crate::spi::Acs37800Spi;
 }
@@ -0,0 +1,853 @@
+use bitfield_struct::bitfield;
+
+use crate::{
+    Acs37800, Acs37800Eeprom, Acs37800EepromExt, Acs37800ReadError, Acs37800Register,
+    Acs37800WriteError, sign_extend,
+};
+
+#[cfg(feature = "async")]
+use crate::{Acs37800Async, Acs37800EepromAsyncExt};
+
+/// ## Rate at which the device samples the voltage and current channels.
+pub const SAMPLE_RATE_HZ: u32 = 32_000;
+
+/// Volatile register 0x20 (ACS37800_REGISTER_20_t)
+///
+/// The one-second (0x26) and one-minute (0x27) RMS averages share this layout.
+///
+/// Bits:
+///   0..=15  : vrms (16 bits, unsigned, 16 fractional bits)
+///   16..=31 : irms (16 bits, unsigned, 16 fractional bits)
+#[bitfield(u32, order = Lsb)]
+pub struct Register20Raw {
+    pub vrms: u16,
+
+    pub irms: u16,
+}
+
+/// RMS voltage and current, as computed by the device over the last averaging window.
+///
+/// The normalized values read the codes as fixed-point fractions of the register range, so a
+/// full-scale input does not reach 1.0 (RMS full scale is 55,000 codes, about 0.84). The readers
+/// in [`Acs37800ScaledExt`](crate::Acs37800ScaledExt) convert against the device full scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800Rms {
+    /// VRMS in register codes.
+    pub vrms_codes: u16,
+    /// VRMS as an unsigned Q0.16 fraction of the register range (0.0..1.0).
+    pub vrms_normalized: f32,
+    /// IRMS in register codes.
+    pub irms_codes: u16,
+    /// IRMS as an unsigned Q0.16 fraction of the register range (0.0..1.0).
+    pub irms_normalized: f32,
+}
+
+impl From<Register20Raw> for Acs37800Rms {
+    fn from(raw: Register20Raw) -> Self {
+        Acs37800Rms {
+            vrms_codes: raw.vrms(),
+            vrms_normalized: unsigned_fraction(raw.vrms(), 16),
+            irms_codes: raw.irms(),
+            irms_normalized: unsigned_fraction(raw.irms(), 16),
+        }
+    }
+}
+
+/// Volatile register 0x21 (ACS37800_REGISTER_21_t)
+/// Bits:
+///   0..=15  : pactive (16 bits, signed, 15 fractional bits)
+///   16..=31 : pimag   (16 bits, signed, 15 fractional bits)
+#[bitfield(u32, order = Lsb)]
+pub struct Register21Raw {
+    pub pactive: u16,
+
+    pub pimag: u16,
+}
+
+/// Active and reactive power, as computed by the device over the last averaging window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800Power {
+    /// Active power in register codes, sign-extended.
+    pub pactive_codes: i16,
+    /// Active power as a signed Q0.15 fraction of the register range (-1.0..1.0).
+    pub pactive_normalized: f32,
+    /// Reactive power in register codes, sign-extended.
+    pub pimag_codes: i16,
+    /// Reactive power as a signed Q0.15 fraction of the register range (-1.0..1.0).
+    pub pimag_normalized: f32,
+}
+
+impl From<Register21Raw> for Acs37800Power {
+    fn from(raw: Register21Raw) -> Self {
+        let pactive_codes = sign_extend(raw.pactive(), 16);
+        let pimag_codes = sign_extend(raw.pimag(), 16);
+
+        Acs37800Power {
+            pactive_codes,
+            pactive_normalized: signed_fraction(pactive_codes, 15),
+            pimag_codes,
+            pimag_normalized: signed_fraction(pimag_codes, 15),
+        }
+    }
+}
+
+/// Volatile register 0x22 (ACS37800_REGISTER_22_t)
+/// Bits:
+///   0..=15  : papparent (16 bits, unsigned, 16 fractional bits)
+///   16..=26 : pfactor   (11 bits, signed, 10 fractional bits)
+///   27      : posangle
+///   28      : pospf
+///   29..=31 : reserved  (3 bits)
+#[bitfield(u32, order = Lsb)]
+pub struct Register22Raw {
+    pub papparent: u16,
+
+    #[bits(11)]
+    pub pfactor: u16,
+
+    pub posangle: bool,
+
+    pub pospf: bool,
+
+    #[bits(3)]
+    _reserved: u8,
+}
+
+/// Phase of the current relative to the voltage (`posangle`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800CurrentPhase {
+    /// Current leads voltage (capacitive load).
+    Leading,
+    /// Current lags voltage (inductive load).
+    Lagging,
+}
+
+/// Direction of the active power flow (`pospf`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800PowerDirection {
+    /// Power is consumed by the load.
+    Import,
+    /// Power is generated and fed back into the line.
+    Export,
+}
+
+/// Apparent power and power factor, as computed by the device over the last averaging window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800ApparentPower {
+    /// Apparent power in register codes.
+    pub papparent_codes: u16,
+    /// Apparent power as an unsigned Q0.16 fraction of the register range (0.0..1.0).
+    pub papparent_normalized: f32,
+    /// Power factor in register codes, sign-extended (-1024..=1023).
+    pub pfactor_codes: i16,
+    /// Signed power factor (-1.0..1.0).
+    pub power_factor: f32,
+    pub current_phase: Acs37800CurrentPhase,
+    pub direction: Acs37800PowerDirection,
+}
+
+impl From<Register22Raw> for Acs37800ApparentPower {
+    fn from(raw: Register22Raw) -> Self {
+        let pfactor_codes = sign_extend(raw.pfactor(), 11);
+
+        let current_phase = if raw.posangle() {
+            Acs37800CurrentPhase::Lagging
+        } else {
+            Acs37800CurrentPhase::Leading
+        };
+
+        let direction = if raw.pospf() {
+            Acs37800PowerDirection::Import
+        } else {
+            Acs37800PowerDirection::Export
+        };
+
+        Acs37800ApparentPower {
+            papparent_codes: raw.papparent(),
+            papparent_normalized: unsigned_fraction(raw.papparent(), 16),
+            pfactor_codes,
+            power_factor: signed_fraction(pfactor_codes, 10),
+            current_phase,
+            direction,
+        }
+    }
+}
+
+/// Volatile register 0x28 (ACS37800_REGISTER_28_t)
+///
+/// The one-minute active power average (0x29) shares this layout.
+///
+/// Bits:
+///   0..=15  : pactavg  (16 bits, signed, 15 fractional bits)
+///   16..=31 : reserved (16 bits)
+#[bitfield(u32, order = Lsb)]
+pub struct Register28Raw {
+    pub pactavg: u16,
+
+    _reserved: u16,
+}
+
+/// Averaged active power.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800ActivePower {
+    /// Active power in register codes, sign-extended.
+    pub pactive_codes: i16,
+    /// Active power as a signed Q0.15 fraction of the register range (-1.0..1.0).
+    pub pactive_normalized: f32,
+}
+
+impl From<Register28Raw> for Acs37800ActivePower {
+    fn from(raw: Register28Raw) -> Self {
+        let pactive_codes = sign_extend(raw.pactavg(), 16);
+
+        Acs37800ActivePower {
+            pactive_codes,
+            pactive_normalized: signed_fraction(pactive_codes, 15),
+        }
+    }
+}
+
+/// On-die one-second and one-minute averages (registers 0x26–0x29).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800Averages {
+    pub rms_one_sec: Acs37800Rms,
+    pub rms_one_min: Acs37800Rms,
+    pub active_power_one_sec: Acs37800ActivePower,
+    pub active_power_one_min: Acs37800ActivePower,
+}
+
+/// Volatile register 0x2A (ACS37800_REGISTER_2A_t)
+/// Bits:
+///   0..=15  : vcodes (16 bits, signed, 15 fractional bits)
+///   16..=31 : icodes (16 bits, signed, 15 fractional bits)
+#[bitfield(u32, order = Lsb)]
+pub struct Register2aRaw {
+    pub vcodes: u16,
+
+    pub icodes: u16,
+}
+
+/// Instantaneous voltage and current sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800InstantaneousSample {
+    /// Voltage sample in register codes, sign-extended.
+    pub vcodes: i16,
+    /// Voltage sample as a signed Q0.15 fraction of the register range (-1.0..1.0).
+    pub vcodes_normalized: f32,
+    /// Current sample in register codes, sign-extended.
+    pub icodes: i16,
+    /// Current sample as a signed Q0.15 fraction of the register range (-1.0..1.0).
+    pub icodes_normalized: f32,
+}
+
+impl From<Register2aRaw> for Acs37800InstantaneousSample {
+    fn from(raw: Register2aRaw) -> Self {
+        let vcodes = sign_extend(raw.vcodes(), 16);
+        let icodes = sign_extend(raw.icodes(), 16);
+
+        Acs37800InstantaneousSample {
+            vcodes,
+            vcodes_normalized: signed_fraction(vcodes, 15),
+            icodes,
+            icodes_normalized: signed_fraction(icodes, 15),
+        }
+    }
+}
+
+/// Volatile register 0x2C (ACS37800_REGISTER_2C_t)
+/// Bits:
+///   0..=31 : pinstant (32 bits, signed, 30 fractional bits)
+#[bitfield(u32, order = Lsb)]
+pub struct Register2cRaw {
+    pub pinstant: u32,
+}
+
+/// Instantaneous power, the product of the latest voltage and current samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800InstantaneousPower {
+    /// Instantaneous power in register codes.
+    pub pinstant_codes: i32,
+    /// Instantaneous power as a signed Q1.30 fraction of the register range (-2.0..2.0).
+    pub pinstant_normalized: f32,
+}
+
+impl From<Register2cRaw> for Acs37800InstantaneousPower {
+    fn from(raw: Register2cRaw) -> Self {
+        let pinstant_codes = raw.pinstant() as i32;
+
+        Acs37800InstantaneousPower {
+            pinstant_codes,
+            // Product of two values with 15 fractional bits each
+            pinstant_normalized: pinstant_codes as f32 / (1u32 << 30) as f32,
+        }
+    }
+}
+
+/// Volatile register 0x2D (ACS37800_REGISTER_2D_t)
+/// Bits:
+///   0       : zerocrossout
+///   1       : faultout
+///   2       : faultlatched (write 1 to clear)
+///   3       : overvoltage
+///   4       : undervoltage
+///   5..=31  : reserved (27 bits)
+#[bitfield(u32, order = Lsb)]
+pub struct Register2dRaw {
+    pub zerocrossout: bool,
+
+    pub faultout: bool,
+
+    pub faultlatched: bool,
+
+    pub overvoltage: bool,
+
+    pub undervoltage: bool,
+
+    #[bits(27)]
+    _reserved: u32,
+}
+
+/// Live status flags.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Acs37800Status {
+    /// Zero-crossing output, mirrors the zero-crossing DIO function.
+    pub zero_cross: bool,
+    /// Overcurrent fault currently present.
+    pub fault: bool,
+    /// Overcurrent fault occurred since the latch was last cleared.
+    pub fault_latched: bool,
+    pub overvoltage: bool,
+    pub undervoltage: bool,
+}
+
+impl From<Register2dRaw> for Acs37800Status {
+    fn from(raw: Register2dRaw) -> Self {
+        Acs37800Status {
+            zero_cross: raw.zerocrossout(),
+            fault: raw.faultout(),
+            fault_latched: raw.faultlatched(),
+            overvoltage: raw.overvoltage(),
+            undervoltage: raw.undervoltage(),
+        }
+    }
+}
+
+/// Volatile register 0x25 (ACS37800_REGISTER_25_t)
+/// Bits:
+///   0..=9   : numptsout (10 bits)
+///   10..=31 : reserved  (22 bits)
+#[bitfield(u32, order = Lsb)]
+pub struct Register25Raw {
+    #[bits(10)]
+    pub numptsout: u16,
+
+    #[bits(22)]
+    _reserved: u32,
+}
+
+/// Window covered by the last RMS calculation.
+///
+/// With `bypass_n_en` cleared the device computes RMS values between voltage zero-crossings, so
+/// the window follows the line frequency. With it set the window is a fixed `n` samples long.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Acs37800AveragingWindow {
+    /// Samples that went into the last RMS calculation (`numptsout`).
+    pub samples: u16,
+    /// Fixed window length in samples, `None` when the window follows zero-crossings.
+    pub configured_samples: Option<u16>,
+}
+
+impl Acs37800AveragingWindow {
+    /// Combines the sample count from register 0x25 with the `n_cycles`/`bypass_n_en`
+    /// configuration.
+    pub fn new(samples: u16, config: &Acs37800Eeprom) -> Self {
+        Self::from_parts(samples, config.n_cycles, config.bypass_n_en)
+    }
+
+    fn from_parts(samples: u16, n: u16, bypass_n_en: bool) -> Self {
+        Acs37800AveragingWindow {
+            samples,
+            configured_samples: bypass_n_en.then_some(n),
+        }
+    }
+
+    /// Window duration in microseconds.
+    pub fn duration_us(&self) -> u32 {
+        // Widened since `samples * 1_000_000` overflows u32, the quotient always fits again
+        (u64::from(self.samples) * 1_000_000 / u64::from(SAMPLE_RATE_HZ)) as u32
+    }
+
+    /// Number of line cycles spanned by the window at the given line frequency.
+    pub fn line_cycles(&self, line_frequency_hz: f32) -> f32 {
+        f32::from(self.samples) * line_frequency_hz / SAMPLE_RATE_HZ as f32
+    }
+
+    /// Whether the window ended before reaching the configured number of samples.
+    ///
+    /// Windows that follow zero-crossings are never considered truncated, use
+    /// [`line_cycles`](Self::line_cycles) to judge them instead.
+    pub fn is_truncated(&self) -> bool {
+        self.configured_samples
+            .is_some_and(|configured| self.samples < configured)
+    }
+}
+
+pub trait Acs37800MeasurementExt: Acs37800 {
+    fn read_rms_raw(&mut self) -> Result<Register20Raw, Acs37800ReadError> {
+        let r20 = Register20Raw(self.read_reg32(Acs37800Register::R20)?);
+        Ok(r20)
+    }
+
+    /// Reads VRMS and IRMS from register 0x20.
+    fn read_rms(&mut self) -> Result<Acs37800Rms, Acs37800ReadError> {
+        Ok(self.read_rms_raw()?.into())
+    }
+
+    fn read_power_raw(&mut self) -> Result<Register21Raw, Acs37800ReadError> {
+        let r21 = Register21Raw(self.read_reg32(Acs37800Register::R21)?);
+        Ok(r21)
+    }
+
+    /// Reads active and reactive power from register 0x21.
+    fn read_power(&mut self) -> Result<Acs37800Power, Acs37800ReadError> {
+        Ok(self.read_power_raw()?.into())
+    }
+
+    fn read_apparent_power_raw(&mut self) -> Result<Register22Raw, Acs37800ReadError> {
+        let r22 = Register22Raw(self.read_reg32(Acs37800Register::R22)?);
+        Ok(r22)
+    }
+
+    /// Reads apparent power, power factor and the lead/lag and direction flags from register
+    /// 0x22.
+    fn read_apparent_power(&mut self) -> Result<Acs37800ApparentPower, Acs37800ReadError> {
+        Ok(self.read_apparent_power_raw()?.into())
+    }
+
+    /// Reads the one-second VRMS and IRMS averages from register 0x26.
+    fn read_rms_avg_one_sec(&mut self) -> Result<Acs37800Rms, Acs37800ReadError> {
+        let r26 = Register20Raw(self.read_reg32(Acs37800Register::R26)?);
+        Ok(r26.into())
+    }
+
+    /// Reads the one-minute VRMS and IRMS averages from register 0x27.
+    fn read_rms_avg_one_min(&mut self) -> Result<Acs37800Rms, Acs37800ReadError> {
+        let r27 = Register20Raw(self.read_reg32(Acs37800Register::R27)?);
+        Ok(r27.into())
+    }
+
+    /// Reads the one-second active power average from register 0x28.
+    fn read_active_power_avg_one_sec(&mut self) -> Result<Acs37800ActivePower, Acs37800ReadError> {
+        let r28 = Register28Raw(self.read_reg32(Acs37800Register::R28)?);
+        Ok(r28.into())
+    }
+
+    /// Reads the one-minute active power average from register 0x29.
+    fn read_active_power_avg_one_min(&mut self) -> Result<Acs37800ActivePower, Acs37800ReadError> {
+        let r29 = Register28Raw(self.read_reg32(Acs37800Register::R29)?);
+        Ok(r29.into())
+    }
+
+    /// Convenience: read every on-die average in one go.
+    fn read_averages(&mut self) -> Result<Acs37800Averages, Acs37800ReadError> {
+        Ok(Acs37800Averages {
+            rms_one_sec: self.read_rms_avg_one_sec()?,
+            rms_one_min: self.read_rms_avg_one_min()?,
+            active_power_one_sec: self.read_active_power_avg_one_sec()?,
+            active_power_one_min: self.read_active_power_avg_one_min()?,
+        })
+    }
+
+    fn read_instantaneous_raw(&mut self) -> Result<Register2aRaw, Acs37800ReadError> {
+        let r2a = Register2aRaw(self.read_reg32(Acs37800Register::R2A)?);
+        Ok(r2a)
+    }
+
+    /// Reads the latest voltage and current samples from register 0x2A.
+    fn read_instantaneous(&mut self) -> Result<Acs37800InstantaneousSample, Acs37800ReadError> {
+        Ok(self.read_instantaneous_raw()?.into())
+    }
+
+    fn read_instantaneous_power_raw(&mut self) -> Result<Register2cRaw, Acs37800ReadError> {
+        let r2c = Register2cRaw(self.read_reg32(Acs37800Register::R2C)?);
+        Ok(r2c)
+    }
+
+    /// Reads the instantaneous power from register 0x2C.
+    fn read_instantaneous_power(
+        &mut self,
+    ) -> Result<Acs37800InstantaneousPower, Acs37800ReadError> {
+        Ok(self.read_instantaneous_power_raw()?.into())
+    }
+
+    fn read_status_raw(&mut self) -> Result<Register2dRaw, Acs37800ReadError> {
+        let r2d = Register2dRaw(self.read_reg32(Acs37800Register::R2D)?);
+        Ok(r2d)
+    }
+
+    /// Reads the status flags from register 0x2D.
+    fn read_status(&mut self) -> Result<Acs37800Status, Acs37800ReadError> {
+        Ok(self.read_status_raw()?.into())
+    }
+
+    fn read_sample_count_raw(&mut self) -> Result<Register25Raw, Acs37800ReadError> {
+        let r25 = Register25Raw(self.read_reg32(Acs37800Register::R25)?);
+        Ok(r25)
+    }
+
+    /// Reads the number of samples used in the last RMS calculation from register 0x25.
+    fn read_sample_count(&mut self) -> Result<u16, Acs37800ReadError> {
+        Ok(self.read_sample_count_raw()?.numptsout())
+    }
+
+    /// Reads the sample count and the live `n`/`bypass_n_en` configuration (shadow register
+    /// 0x1F) to report the window of the last RMS calculation.
+    fn read_averaging_window(&mut self) -> Result<Acs37800AveragingWindow, Acs37800ReadError> {
+        let samples = self.read_sample_count()?;
+        let r0f = self.read_shadow_0f_raw()?;
+        Ok(Acs37800AveragingWindow::from_parts(
+            samples,
+            r0f.n(),
+            r0f.bypass_n_en(),
+        ))
+    }
+
+    /// Clears the latched overcurrent fault by writing its bit in register 0x2D.
+    fn clear_fault_latch(&mut self) -> Result<(), Acs37800WriteError> {
+        let clear = Register2dRaw::new().with_faultlatched(true);
+        self.write_reg32(Acs37800Register::R2D, clear.into_bits())
+    }
+}
+
+impl<T: Acs37800 + ?Sized> Acs37800MeasurementExt for T {}
+
+#[cfg(feature = "async")]
+pub trait Acs37800MeasurementAsyncExt: Acs37800Async {
+    fn read_rms_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Register20Raw, Acs37800ReadError>> + '_ {
+        async {
+            let r20 = Register20Raw(self.read_reg32(Acs37800Register::R20).await?);
+            Ok(r20)
+        }
+    }
+
+    /// Reads VRMS and IRMS from register 0x20.
+    fn read_rms(&mut self) -> impl Future<Output = Result<Acs37800Rms, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_rms_raw().await?.into()) }
+    }
+
+    fn read_power_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Register21Raw, Acs37800ReadError>> + '_ {
+        async {
+            let r21 = Register21Raw(self.read_reg32(Acs37800Register::R21).await?);
+            Ok(r21)
+        }
+    }
+
+    /// Reads active and reactive power from register 0x21.
+    fn read_power(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800Power, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_power_raw().await?.into()) }
+    }
+
+    fn read_apparent_power_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Register22Raw, Acs37800ReadError>> + '_ {
+        async {
+            let r22 = Register22Raw(self.read_reg32(Acs37800Register::R22).await?);
+            Ok(r22)
+        }
+    }
+
+    /// Reads apparent power, power factor and the lead/lag and direction flags from register
+    /// 0x22.
+    fn read_apparent_power(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800ApparentPower, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_apparent_power_raw().await?.into()) }
+    }
+
+    /// Reads the one-second VRMS and IRMS averages from register 0x26.
+    fn read_rms_avg_one_sec(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800Rms, Acs37800ReadError>> + '_ {
+        async {
+            let r26 = Register20Raw(self.read_reg32(Acs37800Register::R26).await?);
+            Ok(r26.into())
+        }
+    }
+
+    /// Reads the one-minute VRMS and IRMS averages from register 0x27.
+    fn read_rms_avg_one_min(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800Rms, Acs37800ReadError>> + '_ {
+        async {
+            let r27 = Register20Raw(self.read_reg32(Acs37800Register::R27).await?);
+            Ok(r27.into())
+        }
+    }
+
+    /// Reads the one-second active power average from register 0x28.
+    fn read_active_power_avg_one_sec(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800ActivePower, Acs37800ReadError>> + '_ {
+        async {
+            let r28 = Register28Raw(self.read_reg32(Acs37800Register::R28).await?);
+            Ok(r28.into())
+        }
+    }
+
+    /// Reads the one-minute active power average from register 0x29.
+    fn read_active_power_avg_one_min(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800ActivePower, Acs37800ReadError>> + '_ {
+        async {
+            let r29 = Register28Raw(self.read_reg32(Acs37800Register::R29).await?);
+            Ok(r29.into())
+        }
+    }
+
+    /// Convenience: read every on-die average in one go.
+    fn read_averages(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800Averages, Acs37800ReadError>> + '_ {
+        async {
+            Ok(Acs37800Averages {
+                rms_one_sec: self.read_rms_avg_one_sec().await?,
+                rms_one_min: self.read_rms_avg_one_min().await?,
+                active_power_one_sec: self.read_active_power_avg_one_sec().await?,
+                active_power_one_min: self.read_active_power_avg_one_min().await?,
+            })
+        }
+    }
+
+    fn read_instantaneous_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Register2aRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r2a = Register2aRaw(self.read_reg32(Acs37800Register::R2A).await?);
+            Ok(r2a)
+        }
+    }
+
+    /// Reads the latest voltage and current samples from register 0x2A.
+    fn read_instantaneous(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800InstantaneousSample, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_instantaneous_raw().await?.into()) }
+    }
+
+    fn read_instantaneous_power_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Register2cRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r2c = Register2cRaw(self.read_reg32(Acs37800Register::R2C).await?);
+            Ok(r2c)
+        }
+    }
+
+    /// Reads the instantaneous power from register 0x2C.
+    fn read_instantaneous_power(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800InstantaneousPower, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_instantaneous_power_raw().await?.into()) }
+    }
+
+    fn read_status_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Register2dRaw, Acs37800ReadError>> + '_ {
+        async {
+            let r2d = Register2dRaw(self.read_reg32(Acs37800Register::R2D).await?);
+            Ok(r2d)
+        }
+    }
+
+    /// Reads the status flags from register 0x2D.
+    fn read_status(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800Status, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_status_raw().await?.into()) }
+    }
+
+    fn read_sample_count_raw(
+        &mut self,
+    ) -> impl Future<Output = Result<Register25Raw, Acs37800ReadError>> + '_ {
+        async {
+            let r25 = Register25Raw(self.read_reg32(Acs37800Register::R25).await?);
+            Ok(r25)
+        }
+    }
+
+    /// Reads the number of samples used in the last RMS calculation from register 0x25.
+    fn read_sample_count(&mut self) -> impl Future<Output = Result<u16, Acs37800ReadError>> + '_ {
+        async { Ok(self.read_sample_count_raw().await?.numptsout()) }
+    }
+
+    /// Reads the sample count and the live `n`/`bypass_n_en` configuration (shadow register
+    /// 0x1F) to report the window of the last RMS calculation.
+    fn read_averaging_window(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800AveragingWindow, Acs37800ReadError>> + '_ {
+        async {
+            let samples = self.read_sample_count().await?;
+            let r0f = self.read_shadow_0f_raw().await?;
+            Ok(Acs37800AveragingWindow::from_parts(
+                samples,
+                r0f.n(),
+                r0f.bypass_n_en(),
+            ))
+        }
+    }
+
+    /// Clears the latched overcurrent fault by writing its bit in register 0x2D.
+    fn clear_fault_latch(&mut self) -> impl Future<Output = Result<(), Acs37800WriteError>> + '_ {
+        let clear = Register2dRaw::new().with_faultlatched(true);
+        self.write_reg32(Acs37800Register::R2D, clear.into_bits())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: Acs37800Async + ?Sized> Acs37800MeasurementAsyncExt for T {}
+
+/// Helper: interpret an unsigned fixed-point code with `fraction_bits` fractional bits.
+fn unsigned_fraction(codes: u16, fraction_bits: u8) -> f32 {
+    f32::from(codes) / (1u32 << fraction_bits) as f32
+}
+
+/// Helper: interpret a signed fixed-point code with `fraction_bits` fractional bits.
+fn signed_fraction(codes: i16, fraction_bits: u8) -> f32 {
+    f32::from(codes) / (1u32 << fraction_bits) as f32
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn rms_decodes_codes_and_fractions() {
+        let rms = Acs37800Rms::from(Register20Raw(0x4000_c000));
+        assert_eq!(rms.vrms_codes, 0xc000);
+        assert_eq!(rms.vrms_normalized, 0.75);
+        assert_eq!(rms.irms_codes, 0x4000);
+        assert_eq!(rms.irms_normalized, 0.25);
+    }
+
+    #[test]
+    fn rms_full_scale_stays_below_one() {
+        let rms = Acs37800Rms::from(Register20Raw(0xffff_ffff));
+        assert!(rms.vrms_normalized < 1.0);
+        assert!(rms.irms_normalized < 1.0);
+    }
+
+    #[test]
+    fn power_sign_extends_both_fields() {
+        let power = Acs37800Power::from(Register21Raw(0x2000_c000));
+        assert_eq!(power.pactive_codes, -0x4000);
+        assert_eq!(power.pactive_normalized, -0.5);
+        assert_eq!(power.pimag_codes, 0x2000);
+        assert_eq!(power.pimag_normalized, 0.25);
+    }
+
+    #[test]
+    fn power_handles_negative_full_scale() {
+        let power = Acs37800Power::from(Register21Raw(0x8000_8000));
+        assert_eq!(power.pactive_codes, i16::MIN);
+        assert_eq!(power.pactive_normalized, -1.0);
+        assert_eq!(power.pimag_normalized, -1.0);
+    }
+
+    #[test]
+    fn apparent_power_decodes_power_factor_and_flags() {
+        // papparent = 0x8000, pfactor = -512 (0x600), posangle = 1, pospf = 0
+        let raw = Register22Raw::new()
+            .with_papparent(0x8000)
+            .with_pfactor(0x600)
+            .with_posangle(true)
+            .with_pospf(false);
+        let apparent = Acs37800ApparentPower::from(raw);
+        assert_eq!(apparent.papparent_codes, 0x8000);
+        assert_eq!(apparent.papparent_normalized, 0.5);
+        assert_eq!(apparent.pfactor_codes, -512);
+        assert_eq!(apparent.power_factor, -0.5);
+        assert_eq!(apparent.current_phase, Acs37800CurrentPhase::Lagging);
+        assert_eq!(apparent.direction, Acs37800PowerDirection::Export);
+    }
+
+    #[test]
+    fn apparent_power_decodes_leading_import() {
+        let raw = Register22Raw::new()
+            .with_pfactor(0x3ff)
+            .with_posangle(false)
+            .with_pospf(true);
+        let apparent = Acs37800ApparentPower::from(raw);
+        assert_eq!(apparent.pfactor_codes, 1023);
+        assert_eq!(apparent.current_phase, Acs37800CurrentPhase::Leading);
+        assert_eq!(apparent.direction, Acs37800PowerDirection::Import);
+    }
+
+    #[test]
+    fn active_power_average_ignores_reserved_bits() {
+        let average = Acs37800ActivePower::from(Register28Raw(0xffff_e000));
+        assert_eq!(average.pactive_codes, -0x2000);
+        assert_eq!(average.pactive_normalized, -0.25);
+    }
+
+    #[test]
+    fn instantaneous_sample_sign_extends_codes() {
+        let sample = Acs37800InstantaneousSample::from(Register2aRaw(0xc000_4000));
+        assert_eq!(sample.vcodes, 0x4000);
+        assert_eq!(sample.vcodes_normalized, 0.5);
+        assert_eq!(sample.icodes, -0x4000);
+        assert_eq!(sample.icodes_normalized, -0.5);
+    }
+
+    #[test]
+    fn instantaneous_power_is_signed() {
+        let power = Acs37800InstantaneousPower::from(Register2cRaw(0xf000_0000));
+        assert_eq!(power.pinstant_codes, -0x1000_0000);
+        assert_eq!(power.pinstant_normalized, -0.25);
+    }
+
+    #[test]
+    fn status_decodes_flags() {
+        let status = Acs37800Status::from(Register2dRaw(0b1_0110));
+        assert_eq!(
+            status,
+            Acs37800Status {
+                zero_cross: false,
+                fault: true,
+                fault_latched: true,
+                overvoltage: false,
+                undervoltage: true,
+            }
+        );
+    }
+
+    #[test]
+    fn zero_crossing_window_spans_line_cycles() {
+        let window = Acs37800AveragingWindow::from_parts(640, 0x3ff, false);
+        assert_eq!(window.configured_samples, None);
+        assert_eq!(window.duration_us(), 20_000);
+        assert_eq!(window.line_cycles(50.0), 1.0);
+        assert!(!window.is_truncated());
+    }
+
+    #[test]
+    fn duration_of_longest_window_does_not_overflow() {
+        let window = Acs37800AveragingWindow::from_parts(u16::MAX, 0, false);
+        assert_eq!(window.duration_us(), 2_047_968);
+    }
+
+    #[test]
+    fn fixed_window_reports_truncation() {
+        let complete = Acs37800AveragingWindow::from_parts(400, 400, true);
+        assert_eq!(complete.configured_samples, Some(400));
+        assert!(!complete.is_truncated());
+
+        let truncated = Acs37800AveragingWindow::from_parts(250, 400, true);
+        assert!(truncated.is_truncated());
+    }
+}
@@ -0,0 +1,50 @@
+/// Register addresses of the ACS37800.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800Register {
+    // EEPROM
+    R0B = 0x0b,
+    R0C = 0x0c,
+    R0D = 0x0d,
+    R0E = 0x0e,
+    R0F = 0x0f,
+
+    // Shadow (volatile copies of the EEPROM registers)
+    R1B = 0x1b,
+    R1C = 0x1c,
+    R1D = 0x1d,
+    R1E = 0x1e,
+    R1F = 0x1f,
+
+    // Volatile
+    /// VRMS and IRMS.
+    R20 = 0x20,
+    /// Active and reactive power.
+    R21 = 0x21,
+    /// Apparent power, power factor and power direction flags.
+    R22 = 0x22,
+    /// Number of samples used in the last RMS calculation.
+    R25 = 0x25,
+    /// One-second VRMS and IRMS averages.
+    R26 = 0x26,
+    /// One-minute VRMS and IRMS averages.
+    R27 = 0x27,
+    /// One-second active power average.
+    R28 = 0x28,
+    /// One-minute active power average.
+    R29 = 0x29,
+    /// Instantaneous voltage and current samples.
+    R2A = 0x2a,
+    /// Instantaneous power.
+    R2C = 0x2c,
+    /// Zero-crossing, fault and voltage event status flags.
+    R2D = 0x2d,
+    /// Customer access code, write [`CUSTOMER_ACCESS_CODE`](crate::CUSTOMER_ACCESS_CODE) to
+    /// unlock writes to the EEPROM and shadow registers.
+    R2F = 0x2f,
+    /// Customer access status, bit 0 is set while write access is granted.
+    R30 = 0x30,
+}
+
+#[deprecated(note = "renamed to `Acs37800Register`")]
+pub type Acs37800EepromRegister = Acs37800Register;
@@ -0,0 +1,466 @@
+use bon::bon;
+
+use crate::{
+    Acs37800, Acs37800ActivePower, Acs37800ApparentPower, Acs37800CurrentPhase,
+    Acs37800MeasurementExt, Acs37800Power, Acs37800PowerDirection, Acs37800RangeError,
+    Acs37800ReadError, Acs37800Rms,
+};
+
+#[cfg(feature = "async")]
+use crate::{Acs37800Async, Acs37800MeasurementAsyncExt};
+
+/// ## Differential voltage across the sense resistor at full scale.
+pub const VOLTAGE_INPUT_FULL_SCALE_V: f32 = 0.25;
+
+/// VRMS and IRMS codes corresponding to a full-scale input.
+const RMS_FULL_SCALE_CODES: f32 = 55_000.0;
+
+/// Fault threshold codes corresponding to the full-scale current, the 8-bit field reaches up to
+/// twice the range.
+const FAULT_CODES_PER_RANGE: f32 = 128.0;
+
+/// VRMS codes per overvoltage/undervoltage threshold code, the 6-bit thresholds are compared
+/// against the upper bits of VRMS.
+const VOLTAGE_THRESHOLD_VRMS_CODES: f32 = 1024.0;
+
+const VOLTAGE_THRESHOLD_MAX_CODE: u8 = 0x3f;
+
+/// Power codes per milliwatt of a 30 A part, at a 1:1 voltage divider.
+const POWER_CODES_PER_MW_30A: f32 = 3.08;
+
+/// ## Current range variant of the populated part.
+///
+/// The ACS37800 is sold in several current ranges, the part number suffix (`-015B3`, `-030B3`,
+/// `-090B3`) selects the full-scale current and therefore the current channel sensitivity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800Variant {
+    /// ACS37800KMACTR-015B3, ±15 A.
+    Range15A,
+    /// ACS37800KMACTR-030B3, ±30 A.
+    Range30A,
+    /// ACS37800KMACTR-090B3, ±90 A.
+    Range90A,
+}
+
+impl Acs37800Variant {
+    /// Full-scale current in amps.
+    pub fn current_range_amps(&self) -> f32 {
+        match self {
+            Acs37800Variant::Range15A => 15.0,
+            Acs37800Variant::Range30A => 30.0,
+            Acs37800Variant::Range90A => 90.0,
+        }
+    }
+
+    /// Converts an overcurrent fault threshold code (`fault`, register 0x0D) to amps.
+    pub fn fault_threshold_amps(&self, codes: u8) -> f32 {
+        f32::from(codes) / FAULT_CODES_PER_RANGE * self.current_range_amps()
+    }
+
+    /// Converts an overcurrent fault threshold in amps to the nearest code.
+    pub fn fault_threshold_codes(&self, amps: f32) -> Result<u8, Acs37800RangeError> {
+        let max = self.fault_threshold_amps(u8::MAX);
+        let amps = Acs37800RangeError::check("fault threshold (A)", amps, 0.0, max)?;
+        Ok(round_to_code(amps / self.current_range_amps() * FAULT_CODES_PER_RANGE) as u8)
+    }
+
+    /// IRMS codes per amp.
+    pub fn irms_codes_per_amp(&self) -> f32 {
+        RMS_FULL_SCALE_CODES / self.current_range_amps()
+    }
+}
+
+/// ## Board-level scaling from register codes to physical units.
+///
+/// The line voltage reaches the device through a resistor divider: `divider_resistance_ohms` is the
+/// total resistance between the line and the sense resistor, `sense_resistance_ohms` the resistor
+/// the device measures across. `variant` is the populated part, it sets the full-scale current.
+///
+/// The builder rejects resistances that would make every conversion infinite or NaN.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Acs37800Scaling {
+    pub divider_resistance_ohms: f32,
+    pub sense_resistance_ohms: f32,
+    pub variant: Acs37800Variant,
+}
+
+#[bon]
+impl Acs37800Scaling {
+    /// Fails unless the sense resistance is positive and the divider resistance is not negative,
+    /// both finite.
+    #[builder]
+    pub fn new(
+        divider_resistance_ohms: f32,
+        sense_resistance_ohms: f32,
+        variant: Acs37800Variant,
+    ) -> Result<Self, Acs37800RangeError> {
+        Ok(Acs37800Scaling {
+            divider_resistance_ohms: Acs37800RangeError::check(
+                "divider resistance (ohms)",
+                divider_resistance_ohms,
+                0.0,
+                f32::MAX,
+            )?,
+            sense_resistance_ohms: Acs37800RangeError::check(
+                "sense resistance (ohms)",
+                sense_resistance_ohms,
+                f32::MIN_POSITIVE,
+                f32::MAX,
+            )?,
+            variant,
+        })
+    }
+}
+
+impl Acs37800Scaling {
+    /// Ratio between the line voltage and the voltage across the sense resistor.
+    pub fn divider_ratio(&self) -> f32 {
+        (self.divider_resistance_ohms + self.sense_resistance_ohms) / self.sense_resistance_ohms
+    }
+
+    /// Full-scale current of the populated part in amps.
+    pub fn current_range_amps(&self) -> f32 {
+        self.variant.current_range_amps()
+    }
+
+    /// Converts VRMS codes to volts.
+    pub fn volts(&self, vrms_codes: u16) -> f32 {
+        self.vrms_volts(f32::from(vrms_codes))
+    }
+
+    /// Converts IRMS codes to amps.
+    pub fn amps(&self, irms_codes: u16) -> f32 {
+        f32::from(irms_codes) / RMS_FULL_SCALE_CODES * self.current_range_amps()
+    }
+
+    /// Converts active power codes to watts.
+    pub fn watts(&self, pactive_codes: i16) -> f32 {
+        self.power(f32::from(pactive_codes))
+    }
+
+    /// Converts reactive power codes to volt-amperes reactive.
+    pub fn vars(&self, pimag_codes: i16) -> f32 {
+        self.power(f32::from(pimag_codes))
+    }
+
+    /// Converts apparent power codes to volt-amperes.
+    pub fn volt_amperes(&self, papparent_codes: u16) -> f32 {
+        self.power(f32::from(papparent_codes))
+    }
+
+    /// Converts an overvoltage/undervoltage threshold code (`overvreg`/`undervreg`, register
+    /// 0x0E) to RMS volts.
+    pub fn voltage_threshold_volts(&self, codes: u8) -> f32 {
+        // In f32, codes from 64 up would overflow the u16 VRMS range
+        self.vrms_volts(f32::from(codes) * VOLTAGE_THRESHOLD_VRMS_CODES)
+    }
+
+    /// Converts an overvoltage/undervoltage threshold in RMS volts to the nearest code.
+    pub fn voltage_threshold_codes(&self, volts: f32) -> Result<u8, Acs37800RangeError> {
+        let max = self.voltage_threshold_volts(VOLTAGE_THRESHOLD_MAX_CODE);
+        let volts = Acs37800RangeError::check("voltage threshold (V)", volts, 0.0, max)?;
+        Ok(round_to_code(volts / max * f32::from(VOLTAGE_THRESHOLD_MAX_CODE)) as u8)
+    }
+
+    fn vrms_volts(&self, codes: f32) -> f32 {
+        codes / RMS_FULL_SCALE_CODES * VOLTAGE_INPUT_FULL_SCALE_V * self.divider_ratio()
+    }
+
+    fn power(&self, codes: f32) -> f32 {
+        let codes_per_mw = POWER_CODES_PER_MW_30A * 30.0 / self.current_range_amps();
+        codes / codes_per_mw / 1000.0 * self.divider_ratio()
+    }
+
+    pub fn rms(&self, rms: &Acs37800Rms) -> Acs37800ScaledRms {
+        Acs37800ScaledRms {
+            volts: self.volts(rms.vrms_codes),
+            amps: self.amps(rms.irms_codes),
+        }
+    }
+
+    pub fn power_values(&self, power: &Acs37800Power) -> Acs37800ScaledPower {
+        Acs37800ScaledPower {
+            watts: self.watts(power.pactive_codes),
+            vars: self.vars(power.pimag_codes),
+        }
+    }
+
+    pub fn apparent_power(&self, apparent: &Acs37800ApparentPower) -> Acs37800ScaledApparentPower {
+        Acs37800ScaledApparentPower {
+            volt_amperes: self.volt_amperes(apparent.papparent_codes),
+            power_factor: apparent.power_factor,
+            current_phase: apparent.current_phase,
+            direction: apparent.direction,
+        }
+    }
+
+    pub fn active_power(&self, active: &Acs37800ActivePower) -> f32 {
+        self.watts(active.pactive_codes)
+    }
+}
+
+/// RMS voltage and current in volts and amps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800ScaledRms {
+    pub volts: f32,
+    pub amps: f32,
+}
+
+/// Active and reactive power in watts and volt-amperes reactive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800ScaledPower {
+    pub watts: f32,
+    pub vars: f32,
+}
+
+/// Apparent power in volt-amperes, with the power factor and flags it was reported with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800ScaledApparentPower {
+    pub volt_amperes: f32,
+    pub power_factor: f32,
+    pub current_phase: Acs37800CurrentPhase,
+    pub direction: Acs37800PowerDirection,
+}
+
+/// On-die averages in physical units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800ScaledAverages {
+    pub rms_one_sec: Acs37800ScaledRms,
+    pub rms_one_min: Acs37800ScaledRms,
+    pub watts_one_sec: f32,
+    pub watts_one_min: f32,
+}
+
+/// Rounds a non-negative value to the nearest register code, `f32::round` needs `std`.
+pub(crate) fn round_to_code(value: f32) -> u32 {
+    (value + 0.5) as u32
+}
+
+/// Readers returning physical units, using the scaling configured on the driver.
+///
+/// Every reader fails with [`Acs37800ReadError::NoScaling`] when the driver has no scaling.
+pub trait Acs37800ScaledExt: Acs37800MeasurementExt {
+    /// Reads VRMS and IRMS from register 0x20 in volts and amps.
+    fn read_rms_scaled(&mut self) -> Result<Acs37800ScaledRms, Acs37800ReadError> {
+        let scaling = self.scaling().ok_or(Acs37800ReadError::NoScaling)?;
+        Ok(scaling.rms(&self.read_rms()?))
+    }
+
+    /// Reads active and reactive power from register 0x21 in watts and VAR.
+    fn read_power_scaled(&mut self) -> Result<Acs37800ScaledPower, Acs37800ReadError> {
+        let scaling = self.scaling().ok_or(Acs37800ReadError::NoScaling)?;
+        Ok(scaling.power_values(&self.read_power()?))
+    }
+
+    /// Reads apparent power from register 0x22 in volt-amperes.
+    fn read_apparent_power_scaled(
+        &mut self,
+    ) -> Result<Acs37800ScaledApparentPower, Acs37800ReadError> {
+        let scaling = self.scaling().ok_or(Acs37800ReadError::NoScaling)?;
+        Ok(scaling.apparent_power(&self.read_apparent_power()?))
+    }
+
+    /// Reads the one-second and one-minute averages (0x26–0x29) in physical units.
+    fn read_averages_scaled(&mut self) -> Result<Acs37800ScaledAverages, Acs37800ReadError> {
+        let scaling = self.scaling().ok_or(Acs37800ReadError::NoScaling)?;
+        let averages = self.read_averages()?;
+        Ok(Acs37800ScaledAverages {
+            rms_one_sec: scaling.rms(&averages.rms_one_sec),
+            rms_one_min: scaling.rms(&averages.rms_one_min),
+            watts_one_sec: scaling.active_power(&averages.active_power_one_sec),
+            watts_one_min: scaling.active_power(&averages.active_power_one_min),
+        })
+    }
+}
+
+impl<T: Acs37800 + ?Sized> Acs37800ScaledExt for T {}
+
+#[cfg(feature = "async")]
+pub trait Acs37800ScaledAsyncExt: Acs37800MeasurementAsyncExt {
+    /// Reads VRMS and IRMS from register 0x20 in volts and amps.
+    fn read_rms_scaled(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800ScaledRms, Acs37800ReadError>> + '_ {
+        async {
+            let scaling = self.scaling().ok_or(Acs37800ReadError::NoScaling)?;
+            Ok(scaling.rms(&self.read_rms().await?))
+        }
+    }
+
+    /// Reads active and reactive power from register 0x21 in watts and VAR.
+    fn read_power_scaled(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800ScaledPower, Acs37800ReadError>> + '_ {
+        async {
+            let scaling = self.scaling().ok_or(Acs37800ReadError::NoScaling)?;
+            Ok(scaling.power_values(&self.read_power().await?))
+        }
+    }
+
+    /// Reads apparent power from register 0x22 in volt-amperes.
+    fn read_apparent_power_scaled(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800ScaledApparentPower, Acs37800ReadError>> + '_ {
+        async {
+            let scaling = self.scaling().ok_or(Acs37800ReadError::NoScaling)?;
+            Ok(scaling.apparent_power(&self.read_apparent_power().await?))
+        }
+    }
+
+    /// Reads the one-second and one-minute averages (0x26–0x29) in physical units.
+    fn read_averages_scaled(
+        &mut self,
+    ) -> impl Future<Output = Result<Acs37800ScaledAverages, Acs37800ReadError>> + '_ {
+        async {
+            let scaling = self.scaling().ok_or(Acs37800ReadError::NoScaling)?;
+            let averages = self.read_averages().await?;
+            Ok(Acs37800ScaledAverages {
+                rms_one_sec: scaling.rms(&averages.rms_one_sec),
+                rms_one_min: scaling.rms(&averages.rms_one_min),
+                watts_one_sec: scaling.active_power(&averages.active_power_one_sec),
+                watts_one_min: scaling.active_power(&averages.active_power_one_min),
+            })
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: Acs37800Async + ?Sized> Acs37800ScaledAsyncExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1 MΩ divider over a 1 kΩ sense resistor, 30 A part.
+    fn scaling() -> Acs37800Scaling {
+        Acs37800Scaling::builder()
+            .divider_resistance_ohms(999_000.0)
+            .sense_resistance_ohms(1_000.0)
+            .variant(Acs37800Variant::Range30A)
+            .build()
+            .expect("valid scaling")
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() <= expected.abs() * 1e-5,
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn full_scale_rms_codes_reach_input_range() {
+        let scaling = scaling();
+        assert_close(scaling.divider_ratio(), 1000.0);
+        assert_close(scaling.volts(55_000), 250.0);
+        assert_close(scaling.amps(55_000), 30.0);
+        assert_close(scaling.amps(27_500), 15.0);
+    }
+
+    #[test]
+    fn builder_rejects_unusable_resistances() {
+        let build = |divider: f32, sense: f32| {
+            Acs37800Scaling::builder()
+                .divider_resistance_ohms(divider)
+                .sense_resistance_ohms(sense)
+                .variant(Acs37800Variant::Range30A)
+                .build()
+        };
+
+        assert!(build(0.0, 1_000.0).is_ok());
+        assert_eq!(
+            build(999_000.0, 0.0).unwrap_err().field,
+            "sense resistance (ohms)"
+        );
+        assert!(build(999_000.0, -1_000.0).is_err());
+        assert!(build(999_000.0, f32::NAN).is_err());
+        assert_eq!(
+            build(-1.0, 1_000.0).unwrap_err().field,
+            "divider resistance (ohms)"
+        );
+        assert!(build(f32::INFINITY, 1_000.0).is_err());
+    }
+
+    #[test]
+    fn power_codes_follow_current_range() {
+        let scaling = scaling();
+        // 3.08 codes per mW at 30 A and a 1:1 divider
+        assert_close(scaling.watts(3080), 1000.0);
+        assert_close(scaling.vars(-3080), -1000.0);
+        assert_close(scaling.volt_amperes(3080), 1000.0);
+
+        let half_range = Acs37800Scaling {
+            variant: Acs37800Variant::Range15A,
+            ..scaling
+        };
+        assert_close(half_range.watts(3080), 500.0);
+    }
+
+    #[test]
+    fn variant_sets_current_range() {
+        let scaling = Acs37800Scaling {
+            variant: Acs37800Variant::Range90A,
+            ..scaling()
+        };
+        assert_close(scaling.current_range_amps(), 90.0);
+        assert_close(scaling.amps(55_000), 90.0);
+        assert_close(
+            Acs37800Variant::Range15A.irms_codes_per_amp(),
+            55_000.0 / 15.0,
+        );
+    }
+
+    #[test]
+    fn fault_threshold_round_trips_through_amps() {
+        let variant = Acs37800Variant::Range30A;
+        assert_close(variant.fault_threshold_amps(128), 30.0);
+        assert_close(variant.fault_threshold_amps(64), 15.0);
+        assert_eq!(variant.fault_threshold_codes(15.0), Ok(64));
+        assert_eq!(variant.fault_threshold_codes(15.1), Ok(64));
+        for codes in [0, 1, 70, 255] {
+            let amps = variant.fault_threshold_amps(codes);
+            assert_eq!(variant.fault_threshold_codes(amps), Ok(codes));
+        }
+    }
+
+    #[test]
+    fn fault_threshold_rejects_out_of_range_currents() {
+        let variant = Acs37800Variant::Range15A;
+        let err = variant
+            .fault_threshold_codes(40.0)
+            .expect_err("above range");
+        assert_eq!(err.value, 40.0);
+        assert_close(err.max, variant.fault_threshold_amps(255));
+        assert!(variant.fault_threshold_codes(-1.0).is_err());
+        assert!(variant.fault_threshold_codes(f32::NAN).is_err());
+    }
+
+    #[test]
+    fn voltage_threshold_round_trips_through_volts() {
+        let scaling = scaling();
+        // 32 codes are 32768 VRMS codes, just under 149 V with a 1000:1 divider
+        assert_close(
+            scaling.voltage_threshold_volts(32),
+            32768.0 / 55_000.0 * 250.0,
+        );
+        for codes in [0, 1, 32, 63] {
+            let volts = scaling.voltage_threshold_volts(codes);
+            assert_eq!(scaling.voltage_threshold_codes(volts), Ok(codes));
+        }
+        assert!(scaling.voltage_threshold_codes(400.0).is_err());
+        assert!(scaling.voltage_threshold_codes(-1.0).is_err());
+    }
+
+    #[test]
+    fn voltage_threshold_volts_past_the_field_does_not_overflow() {
+        let scaling = scaling();
+        assert_close(
+            scaling.voltage_threshold_volts(63),
+            63.0 * 1024.0 / 55_000.0 * 250.0,
+        );
+        assert_close(
+            scaling.voltage_threshold_volts(64),
+            64.0 * 1024.0 / 55_000.0 * 250.0,
+        );
+    }
+}
@@ -0,0 +1,508 @@
+use crate::{
+    Acs37800Eeprom, Acs37800RangeError, Eeprom0dRaw, Eeprom0fRaw, SAMPLE_RATE_HZ, round_to_code,
+};
+
+const RMS_AVG_1_MAX: u8 = 0x7f;
+const RMS_AVG_2_MAX: u16 = 0x3ff;
+
+/// ## Overcurrent fault delay in microseconds, indexed by `fltdly`.
+pub const FAULT_DELAY_US: [f32; 8] = [0.0, 0.0, 4.75, 9.25, 13.75, 18.5, 23.25, 27.75];
+
+/// ## Channel delay in microseconds, indexed by `chan_del_sel`.
+pub const CHANNEL_DELAY_US: [f32; 8] = [0.0, 4.5, 8.75, 13.25, 17.5, 22.0, 26.25, 30.75];
+
+/// ## Current channel coarse gain, indexed by `crs_sns`.
+pub const COARSE_GAIN: [f32; 8] = [1.0, 2.0, 3.0, 3.5, 4.0, 4.5, 5.5, 8.0];
+
+/// Relative gain change per `sns_fine` code.
+const SNS_FINE_STEP: f32 = 1.0 / 2048.0;
+
+/// Effective current channel gain of a coarse setting trimmed by a sign-extended `sns_fine`.
+pub(crate) fn sensitivity_gain(crs_sns: u8, sns_fine_codes: i16) -> f32 {
+    COARSE_GAIN[usize::from(crs_sns & 0x7)] * (1.0 + f32::from(sns_fine_codes) * SNS_FINE_STEP)
+}
+
+/// Quantity fed into the on-die averagers (registers 0x26–0x29).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800AveragedQuantity {
+    /// VRMS, the default.
+    Voltage,
+    /// IRMS, selected by `iavgselen`.
+    Current,
+    /// Active power, selected by `pavgselen`.
+    ActivePower,
+}
+
+/// Length of an averaging window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800WindowLength {
+    pub samples: f32,
+    pub seconds: f32,
+}
+
+impl Acs37800WindowLength {
+    fn from_samples(samples: f32) -> Self {
+        Acs37800WindowLength {
+            samples,
+            seconds: samples / SAMPLE_RATE_HZ as f32,
+        }
+    }
+}
+
+/// Interpreted `rms_avg_1`/`rms_avg_2` configuration.
+///
+/// The first averager (one-second registers) averages `rms_avg_1` consecutive RMS results, the
+/// second (one-minute registers) averages `rms_avg_2` results of the first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800RmsAveraging {
+    pub quantity: Acs37800AveragedQuantity,
+    /// Window of a single RMS calculation.
+    pub rms: Acs37800WindowLength,
+    /// Window of the first averager.
+    pub one_sec: Acs37800WindowLength,
+    /// Window of the second averager.
+    pub one_min: Acs37800WindowLength,
+}
+
+impl Acs37800Eeprom {
+    /// Quantity selected by `iavgsel_enabled` and `pavgsel_enabled`, active power takes
+    /// precedence.
+    pub fn averaged_quantity(&self) -> Acs37800AveragedQuantity {
+        if self.pavgsel_enabled {
+            Acs37800AveragedQuantity::ActivePower
+        } else if self.iavgsel_enabled {
+            Acs37800AveragedQuantity::Current
+        } else {
+            Acs37800AveragedQuantity::Voltage
+        }
+    }
+
+    /// Samples in a single RMS calculation: `n_cycles` when `bypass_n_en` is set, one line cycle
+    /// otherwise.
+    pub fn rms_window_samples(&self, line_frequency_hz: f32) -> f32 {
+        if self.bypass_n_en {
+            f32::from(self.n_cycles)
+        } else {
+            SAMPLE_RATE_HZ as f32 / line_frequency_hz
+        }
+    }
+
+    /// Averaging windows at the given line frequency.
+    pub fn rms_averaging(&self, line_frequency_hz: f32) -> Acs37800RmsAveraging {
+        let rms = self.rms_window_samples(line_frequency_hz);
+        let one_sec = rms * f32::from(self.rms_avg_1);
+        let one_min = one_sec * f32::from(self.rms_avg_2);
+
+        Acs37800RmsAveraging {
+            quantity: self.averaged_quantity(),
+            rms: Acs37800WindowLength::from_samples(rms),
+            one_sec: Acs37800WindowLength::from_samples(one_sec),
+            one_min: Acs37800WindowLength::from_samples(one_min),
+        }
+    }
+
+    /// `rms_avg_1` value closest to a first averager window of `seconds`.
+    pub fn rms_avg_1_for_seconds(
+        &self,
+        seconds: f32,
+        line_frequency_hz: f32,
+    ) -> Result<u8, Acs37800RangeError> {
+        let rms_seconds = self.rms_window_samples(line_frequency_hz) / SAMPLE_RATE_HZ as f32;
+        let count = Acs37800RangeError::check(
+            "rms_avg_1",
+            seconds / rms_seconds,
+            1.0,
+            f32::from(RMS_AVG_1_MAX),
+        )?;
+        Ok(round_to_code(count) as u8)
+    }
+
+    /// `rms_avg_2` value closest to a second averager window of `seconds`, given the current
+    /// `rms_avg_1`.
+    pub fn rms_avg_2_for_seconds(
+        &self,
+        seconds: f32,
+        line_frequency_hz: f32,
+    ) -> Result<u16, Acs37800RangeError> {
+        let one_sec = self.rms_averaging(line_frequency_hz).one_sec.seconds;
+        let count = Acs37800RangeError::check(
+            "rms_avg_2",
+            seconds / one_sec,
+            1.0,
+            f32::from(RMS_AVG_2_MAX),
+        )?;
+        Ok(round_to_code(count) as u16)
+    }
+}
+
+/// Channel delayed by `chan_del_sel` (`ichan_del_en`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800DelayedChannel {
+    Voltage,
+    Current,
+}
+
+/// Phase compensation between the voltage and current channels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acs37800PhaseCompensation {
+    pub channel: Acs37800DelayedChannel,
+    pub delay_us: f32,
+}
+
+impl Acs37800PhaseCompensation {
+    /// Compensation of `degrees` at the given line frequency.
+    pub fn from_degrees(
+        channel: Acs37800DelayedChannel,
+        degrees: f32,
+        line_frequency_hz: f32,
+    ) -> Self {
+        Acs37800PhaseCompensation {
+            channel,
+            delay_us: degrees / 360.0 / line_frequency_hz * 1_000_000.0,
+        }
+    }
+
+    /// Delay expressed as a phase shift at the given line frequency.
+    pub fn degrees(&self, line_frequency_hz: f32) -> f32 {
+        self.delay_us / 1_000_000.0 * line_frequency_hz * 360.0
+    }
+}
+
+/// Index of the table entry closest to `value`, which must not exceed the last entry.
+fn nearest_index(
+    field: &'static str,
+    table: &[f32; 8],
+    value: f32,
+) -> Result<u8, Acs37800RangeError> {
+    let value = Acs37800RangeError::check(field, value, 0.0, table[7])?;
+    let mut best = 0;
+    for (index, entry) in table.iter().enumerate() {
+        if (entry - value).abs() < (table[best] - value).abs() {
+            best = index;
+        }
+    }
+    Ok(best as u8)
+}
+
+impl Eeprom0dRaw {
+    /// Overcurrent fault delay selected by `fltdly`.
+    pub fn fault_delay_us(&self) -> f32 {
+        FAULT_DELAY_US[usize::from(self.fltdly())]
+    }
+
+    /// Sets `fltdly` to the delay closest to `delay_us`.
+    pub fn with_fault_delay_us(self, delay_us: f32) -> Result<Self, Acs37800RangeError> {
+        let fltdly = nearest_index("fault delay (us)", &FAULT_DELAY_US, delay_us)?;
+        Ok(self.with_fltdly(fltdly))
+    }
+
+    /// Phase compensation selected by `chan_del_sel` and `ichan_del_en`.
+    pub fn phase_compensation(&self) -> Acs37800PhaseCompensation {
+        Acs37800PhaseCompensation {
+            channel: delayed_channel(self.ichan_del_en()),
+            delay_us: CHANNEL_DELAY_US[usize::from(self.chan_del_sel())],
+        }
+    }
+
+    /// Sets `chan_del_sel` and `ichan_del_en` to the compensation closest to `compensation`.
+    pub fn with_phase_compensation(
+        self,
+        compensation: Acs37800PhaseCompensation,
+    ) -> Result<Self, Acs37800RangeError> {
+        let chan_del_sel = nearest_index(
+            "channel delay (us)",
+            &CHANNEL_DELAY_US,
+            compensation.delay_us,
+        )?;
+        Ok(self
+            .with_chan_del_sel(chan_del_sel)
+            .with_ichan_del_en(compensation.channel == Acs37800DelayedChannel::Current))
+    }
+}
+
+fn delayed_channel(ichan_del_en: bool) -> Acs37800DelayedChannel {
+    if ichan_del_en {
+        Acs37800DelayedChannel::Current
+    } else {
+        Acs37800DelayedChannel::Voltage
+    }
+}
+
+impl Acs37800Eeprom {
+    /// Overcurrent fault delay in microseconds, `None` if `fault_delay_setting` is past the
+    /// 3-bit field.
+    pub fn fault_delay_us(&self) -> Option<f32> {
+        FAULT_DELAY_US
+            .get(usize::from(self.fault_delay_setting))
+            .copied()
+    }
+
+    /// Phase compensation between the voltage and current channels, `None` if `chan_delay_sel`
+    /// is past the 3-bit field.
+    pub fn phase_compensation(&self) -> Option<Acs37800PhaseCompensation> {
+        let delay_us = *CHANNEL_DELAY_US.get(usize::from(self.chan_delay_sel))?;
+        Some(Acs37800PhaseCompensation {
+            channel: delayed_channel(self.ichan_delay_enabled),
+            delay_us,
+        })
+    }
+}
+
+/// Function of the DIO_0 pin (`dio_0_sel`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800Dio0Function {
+    ZeroCrossing,
+    Overvoltage,
+    Undervoltage,
+    OvervoltageOrUndervoltage,
+}
+
+/// Function of the DIO_1 pin (`dio_1_sel`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Acs37800Dio1Function {
+    Overcurrent,
+    Undervoltage,
+    Overvoltage,
+    /// Overvoltage, undervoltage or latched overcurrent.
+    OvervoltageOrUndervoltageOrOvercurrentLatched,
+}
+
+impl Acs37800Dio0Function {
+    /// Decodes the low two bits of `bits`, every value of the 2-bit field is a function.
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => Acs37800Dio0Function::ZeroCrossing,
+            1 => Acs37800Dio0Function::Overvoltage,
+            2 => Acs37800Dio0Function::Undervoltage,
+            _ => Acs37800Dio0Function::OvervoltageOrUndervoltage,
+        }
+    }
+}
+
+impl TryFrom<u8> for Acs37800Dio0Function {
+    type Error = Acs37800RangeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > 0b11 {
+            return Err(dio_sel_error("dio_0_sel", value));
+        }
+        Ok(Self::from_bits(value))
+    }
+}
+
+impl From<Acs37800Dio0Function> for u8 {
+    fn from(function: Acs37800Dio0Function) -> Self {
+        match function {
+            Acs37800Dio0Function::ZeroCrossing => 0,
+            Acs37800Dio0Function::Overvoltage => 1,
+            Acs37800Dio0Function::Undervoltage => 2,
+            Acs37800Dio0Function::OvervoltageOrUndervoltage => 3,
+        }
+    }
+}
+
+impl Acs37800Dio1Function {
+    /// Decodes the low two bits of `bits`, every value of the 2-bit field is a function.
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => Acs37800Dio1Function::Overcurrent,
+            1 => Acs37800Dio1Function::Undervoltage,
+            2 => Acs37800Dio1Function::Overvoltage,
+            _ => Acs37800Dio1Function::OvervoltageOrUndervoltageOrOvercurrentLatched,
+        }
+    }
+}
+
+impl TryFrom<u8> for Acs37800Dio1Function {
+    type Error = Acs37800RangeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > 0b11 {
+            return Err(dio_sel_error("dio_1_sel", value));
+        }
+        Ok(Self::from_bits(value))
+    }
+}
+
+impl From<Acs37800Dio1Function> for u8 {
+    fn from(function: Acs37800Dio1Function) -> Self {
+        match function {
+            Acs37800Dio1Function::Overcurrent => 0,
+            Acs37800Dio1Function::Undervoltage => 1,
+            Acs37800Dio1Function::Overvoltage => 2,
+            Acs37800Dio1Function::OvervoltageOrUndervoltageOrOvercurrentLatched => 3,
+        }
+    }
+}
+
+fn dio_sel_error(field: &'static str, value: u8) -> Acs37800RangeError {
+    Acs37800RangeError {
+        field,
+        value: f32::from(value),
+        min: 0.0,
+        max: 3.0,
+    }
+}
+
+impl Eeprom0fRaw {
+    /// Function selected for DIO_0.
+    pub fn dio0_function(&self) -> Acs37800Dio0Function {
+        Acs37800Dio0Function::from_bits(self.dio_0_sel())
+    }
+
+    pub fn with_dio0_function(self, function: Acs37800Dio0Function) -> Self {
+        self.with_dio_0_sel(function.into())
+    }
+
+    /// Function selected for DIO_1.
+    pub fn dio1_function(&self) -> Acs37800Dio1Function {
+        Acs37800Dio1Function::from_bits(self.dio_1_sel())
+    }
+
+    pub fn with_dio1_function(self, function: Acs37800Dio1Function) -> Self {
+        self.with_dio_1_sel(function.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Acs37800EepromRaw, Eeprom0bRaw, Eeprom0cRaw, Eeprom0fRaw};
+
+    use super::*;
+
+    fn eeprom(r0b: Eeprom0bRaw, r0c: Eeprom0cRaw, r0f: Eeprom0fRaw) -> Acs37800Eeprom {
+        Acs37800EepromRaw::builder()
+            .r0b(r0b)
+            .r0c(r0c)
+            .r0d(0)
+            .r0e(0)
+            .r0f(r0f)
+            .build()
+            .into()
+    }
+
+    #[test]
+    fn zero_crossing_windows_follow_line_frequency() {
+        let eeprom = eeprom(
+            Eeprom0bRaw::new(),
+            Eeprom0cRaw::new().with_rms_avg_1(50).with_rms_avg_2(60),
+            Eeprom0fRaw::new(),
+        );
+
+        let averaging = eeprom.rms_averaging(50.0);
+        assert_eq!(averaging.quantity, Acs37800AveragedQuantity::Voltage);
+        assert_eq!(averaging.rms.samples, 640.0);
+        assert_eq!(averaging.one_sec.seconds, 1.0);
+        assert_eq!(averaging.one_min.seconds, 60.0);
+
+        assert_eq!(eeprom.rms_avg_1_for_seconds(1.0, 60.0), Ok(60));
+        assert_eq!(eeprom.rms_avg_2_for_seconds(30.0, 50.0), Ok(30));
+    }
+
+    #[test]
+    fn fixed_windows_use_n() {
+        let eeprom = eeprom(
+            Eeprom0bRaw::new().with_iavgselen(true),
+            Eeprom0cRaw::new().with_rms_avg_1(10).with_rms_avg_2(1),
+            Eeprom0fRaw::new().with_n(320).with_bypass_n_en(true),
+        );
+
+        let averaging = eeprom.rms_averaging(60.0);
+        assert_eq!(averaging.quantity, Acs37800AveragedQuantity::Current);
+        assert_eq!(averaging.rms.samples, 320.0);
+        assert_eq!(averaging.one_sec.samples, 3200.0);
+        assert_eq!(averaging.one_sec.seconds, 0.1);
+    }
+
+    #[test]
+    fn window_conversion_rejects_unreachable_lengths() {
+        let eeprom = eeprom(Eeprom0bRaw::new(), Eeprom0cRaw::new(), Eeprom0fRaw::new());
+        assert!(eeprom.rms_avg_1_for_seconds(10.0, 50.0).is_err());
+        assert!(eeprom.rms_avg_1_for_seconds(0.0, 50.0).is_err());
+    }
+
+    #[test]
+    fn fault_delay_round_trips_through_microseconds() {
+        let r0d = Eeprom0dRaw::new().with_fltdly(3);
+        assert_eq!(r0d.fault_delay_us(), 9.25);
+
+        let r0d = r0d.with_fault_delay_us(20.0).expect("in range");
+        assert_eq!(r0d.fltdly(), 5);
+        assert!(r0d.with_fault_delay_us(50.0).is_err());
+    }
+
+    #[test]
+    fn eeprom_delays_reject_settings_past_the_field() {
+        let mut eeprom = eeprom(Eeprom0bRaw::new(), Eeprom0cRaw::new(), Eeprom0fRaw::new());
+        eeprom.fault_delay_setting = 3;
+        eeprom.chan_delay_sel = 7;
+        assert_eq!(eeprom.fault_delay_us(), Some(9.25));
+        assert_eq!(eeprom.phase_compensation().map(|c| c.delay_us), Some(30.75));
+
+        eeprom.fault_delay_setting = 8;
+        eeprom.chan_delay_sel = 8;
+        assert_eq!(eeprom.fault_delay_us(), None);
+        assert_eq!(eeprom.phase_compensation(), None);
+    }
+
+    #[test]
+    fn phase_compensation_round_trips_through_degrees() {
+        let compensation =
+            Acs37800PhaseCompensation::from_degrees(Acs37800DelayedChannel::Current, 0.3, 60.0);
+        assert!((compensation.delay_us - 13.889).abs() < 1e-3);
+
+        let r0d = Eeprom0dRaw::new()
+            .with_phase_compensation(compensation)
+            .expect("in range");
+        assert!(r0d.ichan_del_en());
+        assert_eq!(r0d.chan_del_sel(), 3);
+
+        let decoded = r0d.phase_compensation();
+        assert_eq!(decoded.channel, Acs37800DelayedChannel::Current);
+        assert_eq!(decoded.delay_us, 13.25);
+        assert!((decoded.degrees(60.0) - 0.2862).abs() < 1e-3);
+    }
+
+    #[test]
+    fn dio_functions_round_trip_through_eeprom_0f() {
+        let r0f = Eeprom0fRaw::new()
+            .with_dio0_function(Acs37800Dio0Function::OvervoltageOrUndervoltage)
+            .with_dio1_function(Acs37800Dio1Function::Overvoltage);
+        assert_eq!(r0f.dio_0_sel(), 3);
+        assert_eq!(r0f.dio_1_sel(), 2);
+        assert_eq!(
+            r0f.dio0_function(),
+            Acs37800Dio0Function::OvervoltageOrUndervoltage
+        );
+        assert_eq!(r0f.dio1_function(), Acs37800Dio1Function::Overvoltage);
+    }
+
+    #[test]
+    fn sensitivity_combines_coarse_and_fine_gain() {
+        assert_eq!(sensitivity_gain(0, 0), 1.0);
+        assert_eq!(sensitivity_gain(4, 0), 4.0);
+        // sns_fine endpoints, -512..=511
+        assert_eq!(sensitivity_gain(7, -512), 6.0);
+        assert_eq!(sensitivity_gain(7, 511), 9.996_094);
+        assert_eq!(sensitivity_gain(4, -454), 3.1132812);
+    }
+
+    #[test]
+    fn dio_selection_rejects_reserved_values() {
+        let err = Acs37800Dio0Function::try_from(4).expect_err("reserved");
+        assert_eq!(err.field, "dio_0_sel");
+        assert!(Acs37800Dio1Function::try_from(0xff).is_err());
+
+        for bits in 0..=3 {
+            let r0f = Eeprom0fRaw::new().with_dio_0_sel(bits).with_dio_1_sel(bits);
+            assert_eq!(
+                Acs37800Dio0Function::try_from(bits),
+                Ok(r0f.dio0_function())
+            );
+            assert_eq!(
+                Acs37800Dio1Function::try_from(bits),
+                Ok(r0f.dio1_function())
+            );
+        }
+    }
+}
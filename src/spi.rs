@@ -1,8 +1,456 @@
 //! # SPI Driver for ACS37800 Power Monitoring IC
 //!
 //! <div style="border-left: 4px solid #812ae4ff; padding: 0.5em 1em;">
-//! <strong>NOTICE</strong><br/>
-//! The SPI driver is a work in-progress. It is not yet functional.
+//! <strong>IMPORTANT</strong><br/>
+//! This module provides a driver for the SPI variants of the Allegro Microsystems ACS37800 power
+//! monitoring IC. If you are using the I²C variant, please refer to the <code>i2c</code> module.
 //! </div>
+//!
+//! ## SPI Frames
+//!
+//! Every access is a single 40-bit frame, sent MSB first. The first byte holds the
+//! read/write flag in its top bit (`1` for a read) followed by the 7-bit register address, the
+//! remaining four bytes carry the big-endian register value.
+//!
+//! | Byte 0                  | Bytes 1..=4             |
+//! |-------------------------|-------------------------|
+//! | `R/W` \| `ADDR[6:0]`    | `DATA[31:0]`            |
+//!
+//! Reads are pipelined: the device shifts out the register requested by the *previous* frame. The
+//! driver therefore sends the read command twice, with chip select released in between, and takes
+//! the value from the second frame.
+
+use bon::Builder;
+
+use embedded_hal::spi::{Error, SpiDevice};
+
+#[cfg(feature = "async")]
+use embedded_hal_async::spi::SpiDevice as AsyncSpiDevice;
+
+use super::{
+    Acs37800, Acs37800BusError, Acs37800BusErrorKind, Acs37800BusOperation, Acs37800ReadError,
+    Acs37800Register, Acs37800Scaling, Acs37800WriteError,
+};
+
+/// Read/write flag in the first byte of a frame, set for reads.
+const SPI_READ: u8 = 0x80;
+
+/// Driver for the SPI variants, implements [`Acs37800`] over a blocking bus and
+/// `Acs37800Async` over an async one when the `async` feature is enabled.
+#[derive(Builder)]
+pub struct Acs37800Spi<SPI> {
+    spi: SPI,
+    /// Board scaling for the readers in [`Acs37800ScaledExt`](crate::Acs37800ScaledExt).
+    scaling: Option<Acs37800Scaling>,
+}
+
+impl<SPI: SpiDevice> Acs37800 for Acs37800Spi<SPI> {
+    fn read_reg32(&mut self, reg: Acs37800Register) -> Result<u32, Acs37800ReadError> {
+        let mut buf = read_frame(reg);
+
+        // The first frame only latches the address, the second one returns its content
+        let mut result = self.spi.transfer_in_place(&mut buf);
+        if result.is_ok() {
+            buf = read_frame(reg);
+            result = self.spi.transfer_in_place(&mut buf);
+        }
+        result.map_err(|cause| bus_error(reg, Acs37800BusOperation::Read, cause))?;
+
+        Ok(frame_value(&buf))
+    }
+
+    fn write_reg32(&mut self, reg: Acs37800Register, value: u32) -> Result<(), Acs37800WriteError> {
+        let buf = write_frame(reg, value);
+
+        let result = self.spi.write(&buf);
+        result.map_err(|cause| bus_error(reg, Acs37800BusOperation::Write, cause))?;
+
+        Ok(())
+    }
+
+    fn scaling(&self) -> Option<Acs37800Scaling> {
+        self.scaling
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI: AsyncSpiDevice> crate::Acs37800Async for Acs37800Spi<SPI> {
+    async fn read_reg32(&mut self, reg: Acs37800Register) -> Result<u32, Acs37800ReadError> {
+        let mut buf = read_frame(reg);
+
+        // The first frame only latches the address, the second one returns its content
+        let mut result = self.spi.transfer_in_place(&mut buf).await;
+        if result.is_ok() {
+            buf = read_frame(reg);
+            result = self.spi.transfer_in_place(&mut buf).await;
+        }
+        result.map_err(|cause| bus_error(reg, Acs37800BusOperation::Read, cause))?;
+
+        Ok(frame_value(&buf))
+    }
+
+    async fn write_reg32(
+        &mut self,
+        reg: Acs37800Register,
+        value: u32,
+    ) -> Result<(), Acs37800WriteError> {
+        let buf = write_frame(reg, value);
+
+        let result = self.spi.write(&buf).await;
+        result.map_err(|cause| bus_error(reg, Acs37800BusOperation::Write, cause))?;
+
+        Ok(())
+    }
+
+    fn scaling(&self) -> Option<Acs37800Scaling> {
+        self.scaling
+    }
+}
+
+/// Read command for `reg`, the data bytes are clocked out as zeros.
+fn read_frame(reg: Acs37800Register) -> [u8; 5] {
+    [SPI_READ | reg as u8, 0, 0, 0, 0]
+}
+
+/// Write command for `reg` followed by the big-endian register value.
+fn write_frame(reg: Acs37800Register, value: u32) -> [u8; 5] {
+    let [b0, b1, b2, b3] = value.to_be_bytes();
+    [reg as u8 & !SPI_READ, b0, b1, b2, b3]
+}
+
+/// Failed transfer to or from `reg`, keeping the kind of bus error.
+fn bus_error(
+    reg: Acs37800Register,
+    operation: Acs37800BusOperation,
+    cause: impl Error,
+) -> Acs37800BusError {
+    Acs37800BusError {
+        register: reg,
+        operation,
+        kind: Acs37800BusErrorKind::Spi(cause.kind()),
+    }
+}
+
+/// Register value shifted out in the data bytes of a frame.
+fn frame_value(buf: &[u8; 5]) -> u32 {
+    let [_, b0, b1, b2, b3] = *buf;
+    u32::from_be_bytes([b0, b1, b2, b3])
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_hal::spi::{ErrorKind, Operation};
+    use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+    use crate::test::{assert_is_bus_error, assert_is_write_bus_error};
+    use crate::{Acs37800MeasurementExt, Acs37800ScaledExt, Acs37800Variant};
+
+    use super::*;
+
+    fn new_driver(expectations: &[SpiTransaction<u8>]) -> Acs37800Spi<SpiMock<u8>> {
+        let spi = SpiMock::new(expectations);
+        Acs37800Spi::builder().spi(spi).build()
+    }
+
+    /// SPI device failing every transaction.
+    struct FailingSpi;
+
+    impl embedded_hal::spi::ErrorType for FailingSpi {
+        type Error = ErrorKind;
+    }
+
+    impl SpiDevice for FailingSpi {
+        fn transaction(&mut self, _operations: &mut [Operation<'_, u8>]) -> Result<(), ErrorKind> {
+            Err(ErrorKind::Other)
+        }
+    }
+
+    /// Both frames of a read of `reg`, the second one returning `value`.
+    fn read_transactions(reg: Acs37800Register, value: u32) -> Vec<SpiTransaction<u8>> {
+        let command = vec![0x80 | reg as u8, 0, 0, 0, 0];
+        let mut response = vec![0];
+        response.extend(value.to_be_bytes());
+        vec![
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer_in_place(command.clone(), vec![0; 5]),
+            SpiTransaction::transaction_end(),
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer_in_place(command, response),
+            SpiTransaction::transaction_end(),
+        ]
+    }
+
+    #[test]
+    fn read_reg32_returns_be_word_from_second_frame() {
+        let expectations = read_transactions(Acs37800Register::R0C, 0x1234_5678);
+        let mut driver = new_driver(&expectations);
+
+        let value = driver
+            .read_reg32(Acs37800Register::R0C)
+            .expect("read value");
+        assert_eq!(value, 0x1234_5678);
+
+        driver.spi.done();
+    }
+
+    #[test]
+    fn read_reg32_maps_bus_errors() {
+        let mut driver = Acs37800Spi::builder().spi(FailingSpi).build();
+
+        let err = driver
+            .read_reg32(Acs37800Register::R0B)
+            .expect_err("should propagate error");
+        assert_is_bus_error(&err);
+        assert!(matches!(
+            err,
+            Acs37800ReadError::Io(Acs37800BusError {
+                register: Acs37800Register::R0B,
+                kind: Acs37800BusErrorKind::Spi(ErrorKind::Other),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn write_reg32_sends_be_word() {
+        let expectations = [
+            SpiTransaction::transaction_start(),
+            SpiTransaction::write_vec(vec![Acs37800Register::R0E as u8, 0x12, 0x34, 0x56, 0x78]),
+            SpiTransaction::transaction_end(),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        driver
+            .write_reg32(Acs37800Register::R0E, 0x1234_5678)
+            .expect("write value");
+
+        driver.spi.done();
+    }
+
+    #[test]
+    fn write_reg32_maps_bus_errors() {
+        let mut driver = Acs37800Spi::builder().spi(FailingSpi).build();
+
+        let err = driver
+            .write_reg32(Acs37800Register::R0F, 0)
+            .expect_err("should propagate error");
+        assert_is_write_bus_error(&err);
+    }
+
+    #[test]
+    fn read_rms_decodes_register_0x20() {
+        let expectations = read_transactions(Acs37800Register::R20, 0x4000_c000);
+        let mut driver = new_driver(&expectations);
+
+        let rms = driver.read_rms().expect("rms");
+        assert_eq!(rms.vrms_codes, 0xc000);
+        assert_eq!(rms.irms_codes, 0x4000);
+
+        driver.spi.done();
+    }
+
+    #[test]
+    fn read_rms_scaled_uses_driver_scaling() {
+        // 27500 codes each, half of the full-scale input
+        let expectations = read_transactions(Acs37800Register::R20, 0x6b6c_6b6c);
+        let spi = SpiMock::new(&expectations);
+        let scaling = Acs37800Scaling::builder()
+            .divider_resistance_ohms(999_000.0)
+            .sense_resistance_ohms(1_000.0)
+            .variant(Acs37800Variant::Range90A)
+            .build()
+            .expect("valid scaling");
+        let mut driver = Acs37800Spi::builder().spi(spi).scaling(scaling).build();
+
+        let rms = driver.read_rms_scaled().expect("rms");
+        assert!((rms.volts - 125.0).abs() < 1e-3);
+        assert!((rms.amps - 45.0).abs() < 1e-3);
+
+        driver.spi.done();
+    }
+
+    #[test]
+    fn read_status_and_clear_fault_latch() {
+        let mut expectations = read_transactions(Acs37800Register::R2D, 0b0000_0110);
+        expectations.extend([
+            SpiTransaction::transaction_start(),
+            SpiTransaction::write_vec(vec![
+                Acs37800Register::R2D as u8,
+                0x00,
+                0x00,
+                0x00,
+                0b0000_0100,
+            ]),
+            SpiTransaction::transaction_end(),
+        ]);
+        let mut driver = new_driver(&expectations);
+
+        let status = driver.read_status().expect("status");
+        assert!(status.fault);
+        assert!(status.fault_latched);
+        assert!(!status.overvoltage);
+        driver.clear_fault_latch().expect("cleared");
+
+        driver.spi.done();
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use embedded_hal::spi::ErrorKind;
+    use embedded_hal_async::spi::Operation;
+    use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+    use crate::spi::Acs37800Spi;
+    use crate::test::{assert_is_bus_error, assert_is_write_bus_error};
+    use crate::{
+        Acs37800Async, Acs37800MeasurementAsyncExt, Acs37800Register, Acs37800ScaledAsyncExt,
+        Acs37800Scaling, Acs37800Variant,
+    };
+
+    fn new_driver(expectations: &[SpiTransaction<u8>]) -> Acs37800Spi<SpiMock<u8>> {
+        let spi = SpiMock::new(expectations);
+        Acs37800Spi::builder().spi(spi).build()
+    }
+
+    /// SPI device failing every transaction.
+    struct FailingSpi;
+
+    impl embedded_hal::spi::ErrorType for FailingSpi {
+        type Error = ErrorKind;
+    }
+
+    impl embedded_hal_async::spi::SpiDevice for FailingSpi {
+        async fn transaction(
+            &mut self,
+            _operations: &mut [Operation<'_, u8>],
+        ) -> Result<(), ErrorKind> {
+            Err(ErrorKind::Other)
+        }
+    }
+
+    /// Both frames of a read of `reg`, the second one returning `value`.
+    fn read_transactions(reg: Acs37800Register, value: u32) -> Vec<SpiTransaction<u8>> {
+        let command = vec![0x80 | reg as u8, 0, 0, 0, 0];
+        let mut response = vec![0];
+        response.extend(value.to_be_bytes());
+        vec![
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer_in_place(command.clone(), vec![0; 5]),
+            SpiTransaction::transaction_end(),
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer_in_place(command, response),
+            SpiTransaction::transaction_end(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn read_reg32_returns_be_word_from_second_frame_async() {
+        let expectations = read_transactions(Acs37800Register::R0C, 0x1234_5678);
+        let mut driver = new_driver(&expectations);
+
+        let value = driver
+            .read_reg32(Acs37800Register::R0C)
+            .await
+            .expect("read value");
+        assert_eq!(value, 0x1234_5678);
+
+        driver.spi.done();
+    }
+
+    #[tokio::test]
+    async fn read_reg32_maps_bus_errors_async() {
+        let mut driver = Acs37800Spi::builder().spi(FailingSpi).build();
+
+        let err = driver
+            .read_reg32(Acs37800Register::R0B)
+            .await
+            .expect_err("should propagate error");
+        assert_is_bus_error(&err);
+    }
+
+    #[tokio::test]
+    async fn write_reg32_sends_be_word_async() {
+        let expectations = [
+            SpiTransaction::transaction_start(),
+            SpiTransaction::write_vec(vec![Acs37800Register::R0E as u8, 0x12, 0x34, 0x56, 0x78]),
+            SpiTransaction::transaction_end(),
+        ];
+        let mut driver = new_driver(&expectations);
+
+        driver
+            .write_reg32(Acs37800Register::R0E, 0x1234_5678)
+            .await
+            .expect("write value");
+
+        driver.spi.done();
+    }
+
+    #[tokio::test]
+    async fn write_reg32_maps_bus_errors_async() {
+        let mut driver = Acs37800Spi::builder().spi(FailingSpi).build();
+
+        let err = driver
+            .write_reg32(Acs37800Register::R0F, 0)
+            .await
+            .expect_err("should propagate error");
+        assert_is_write_bus_error(&err);
+    }
+
+    #[tokio::test]
+    async fn read_rms_decodes_register_0x20_async() {
+        let expectations = read_transactions(Acs37800Register::R20, 0x4000_c000);
+        let mut driver = new_driver(&expectations);
+
+        let rms = driver.read_rms().await.expect("rms");
+        assert_eq!(rms.vrms_codes, 0xc000);
+        assert_eq!(rms.irms_codes, 0x4000);
+
+        driver.spi.done();
+    }
+
+    #[tokio::test]
+    async fn read_rms_scaled_uses_driver_scaling_async() {
+        // 27500 codes each, half of the full-scale input
+        let expectations = read_transactions(Acs37800Register::R20, 0x6b6c_6b6c);
+        let spi = SpiMock::new(&expectations);
+        let scaling = Acs37800Scaling::builder()
+            .divider_resistance_ohms(999_000.0)
+            .sense_resistance_ohms(1_000.0)
+            .variant(Acs37800Variant::Range90A)
+            .build()
+            .expect("valid scaling");
+        let mut driver = Acs37800Spi::builder().spi(spi).scaling(scaling).build();
+
+        let rms = driver.read_rms_scaled().await.expect("rms");
+        assert!((rms.volts - 125.0).abs() < 1e-3);
+        assert!((rms.amps - 45.0).abs() < 1e-3);
+
+        driver.spi.done();
+    }
+
+    #[tokio::test]
+    async fn read_status_and_clear_fault_latch_async() {
+        let mut expectations = read_transactions(Acs37800Register::R2D, 0b0000_0110);
+        expectations.extend([
+            SpiTransaction::transaction_start(),
+            SpiTransaction::write_vec(vec![
+                Acs37800Register::R2D as u8,
+                0x00,
+                0x00,
+                0x00,
+                0b0000_0100,
+            ]),
+            SpiTransaction::transaction_end(),
+        ]);
+        let mut driver = new_driver(&expectations);
+
+        let status = driver.read_status().await.expect("status");
+        assert!(status.fault);
+        assert!(status.fault_latched);
+        assert!(!status.overvoltage);
+        driver.clear_fault_latch().await.expect("cleared");
 
-compile_error!("The SPI driver is not yet implemented.");
+        driver.spi.done();
+    }
+}
@@ -1,15 +1,170 @@
-use crate::Acs37800ReadError;
+use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+    Acs37800, Acs37800BusError, Acs37800BusErrorKind, Acs37800BusOperation, Acs37800ReadError,
+    Acs37800Register, Acs37800WriteError, CUSTOMER_ACCESS_CODE, EEPROM_DATA_MASK,
+};
+
+#[cfg(feature = "async")]
+use crate::Acs37800Async;
+
+/// In-memory register file standing in for a device in sync and async tests.
+///
+/// Writing the customer access code to 0x2F flips the access status in 0x30, like the real
+/// device does.
+#[derive(Default)]
+pub(crate) struct MockDevice {
+    regs: HashMap<Acs37800Register, u32>,
+    fail_on: Option<Acs37800Register>,
+    read_only: Option<Acs37800Register>,
+    stores_ecc: bool,
+    pub(crate) writes: Vec<(Acs37800Register, u32)>,
+}
+
+impl MockDevice {
+    pub(crate) fn set_reg(&mut self, reg: Acs37800Register, value: u32) {
+        self.regs.insert(reg, value);
+    }
+
+    pub(crate) fn with_failure(reg: Acs37800Register) -> Self {
+        Self {
+            fail_on: Some(reg),
+            ..Self::default()
+        }
+    }
+
+    /// Device whose `reg` silently keeps its old content when written.
+    pub(crate) fn with_read_only(reg: Acs37800Register) -> Self {
+        Self {
+            read_only: Some(reg),
+            ..Self::default()
+        }
+    }
+
+    /// Device that sets the ECC bits of EEPROM words on its own, whatever was written.
+    pub(crate) fn with_device_ecc() -> Self {
+        Self {
+            stores_ecc: true,
+            ..Self::default()
+        }
+    }
+
+    fn read_word(&mut self, reg: Acs37800Register) -> Result<u32, Acs37800ReadError> {
+        if self.fail_on == Some(reg) {
+            return Err(bus_error(reg));
+        }
+        self.regs.get(&reg).copied().ok_or_else(|| bus_error(reg))
+    }
+
+    fn write_word(&mut self, reg: Acs37800Register, value: u32) -> Result<(), Acs37800WriteError> {
+        if self.fail_on == Some(reg) {
+            return Err(write_bus_error(reg));
+        }
+        self.writes.push((reg, value));
+        match reg {
+            Acs37800Register::R2F => {
+                let granted = value == CUSTOMER_ACCESS_CODE;
+                self.regs.insert(Acs37800Register::R30, u32::from(granted));
+            }
+            _ if self.read_only == Some(reg) => {}
+            Acs37800Register::R0B
+            | Acs37800Register::R0C
+            | Acs37800Register::R0D
+            | Acs37800Register::R0E
+            | Acs37800Register::R0F
+                if self.stores_ecc =>
+            {
+                self.regs.insert(reg, value | !EEPROM_DATA_MASK);
+            }
+            _ => {
+                self.regs.insert(reg, value);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Acs37800 for MockDevice {
+    fn read_reg32(&mut self, reg: Acs37800Register) -> Result<u32, Acs37800ReadError> {
+        self.read_word(reg)
+    }
+
+    fn write_reg32(&mut self, reg: Acs37800Register, value: u32) -> Result<(), Acs37800WriteError> {
+        self.write_word(reg, value)
+    }
+}
+
+/// [`MockDevice`] behind the async register access, kept a separate type so the blocking and
+/// async helpers never apply to the same device.
+#[cfg(feature = "async")]
+#[derive(Default)]
+pub(crate) struct AsyncMockDevice(pub(crate) MockDevice);
+
+#[cfg(feature = "async")]
+impl Acs37800Async for AsyncMockDevice {
+    fn read_reg32(
+        &mut self,
+        reg: Acs37800Register,
+    ) -> impl Future<Output = Result<u32, Acs37800ReadError>> {
+        let result = self.0.read_word(reg);
+        async move { result }
+    }
+
+    fn write_reg32(
+        &mut self,
+        reg: Acs37800Register,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Acs37800WriteError>> {
+        let result = self.0.write_word(reg, value);
+        async move { result }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Deref for AsyncMockDevice {
+    type Target = MockDevice;
+
+    fn deref(&self) -> &MockDevice {
+        &self.0
+    }
+}
+
+#[cfg(feature = "async")]
+impl DerefMut for AsyncMockDevice {
+    fn deref_mut(&mut self) -> &mut MockDevice {
+        &mut self.0
+    }
+}
+
+pub(crate) fn bus_error(reg: Acs37800Register) -> Acs37800ReadError {
+    Acs37800ReadError::Io(mock_bus_error(reg, Acs37800BusOperation::Read))
+}
+
+pub(crate) fn write_bus_error(reg: Acs37800Register) -> Acs37800WriteError {
+    Acs37800WriteError::Io(mock_bus_error(reg, Acs37800BusOperation::Write))
+}
+
+fn mock_bus_error(reg: Acs37800Register, operation: Acs37800BusOperation) -> Acs37800BusError {
+    Acs37800BusError {
+        register: reg,
+        operation,
+        kind: Acs37800BusErrorKind::I2c(embedded_hal::i2c::ErrorKind::Other),
+    }
+}
 
 pub(crate) fn assert_is_bus_error(err: &Acs37800ReadError) {
-    #[cfg(feature = "std")]
     assert!(
-        matches!(err, Acs37800ReadError::Io(_)),
+        matches!(err, Acs37800ReadError::Io(bus) if bus.operation == Acs37800BusOperation::Read),
         "unexpected error: {err:?}"
     );
+}
 
-    #[cfg(not(feature = "std"))]
+#[cfg(any(feature = "i2c", feature = "spi"))]
+pub(crate) fn assert_is_write_bus_error(err: &Acs37800WriteError) {
     assert!(
-        matches!(err, Acs37800ReadError::Io),
+        matches!(err, Acs37800WriteError::Io(bus) if bus.operation == Acs37800BusOperation::Write),
         "unexpected error: {err:?}"
     );
 }